@@ -48,6 +48,7 @@ fn main() {
     };
     
     let time = Instant::now();
+    #[allow(deprecated)]
     event_loop.run(|event, target| {
         match event {
             Event::AboutToWait => {
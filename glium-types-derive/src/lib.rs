@@ -0,0 +1,144 @@
+//! derive macros for `glium-types`. use them through `glium_types::layout` instead of depending
+//! on this crate directly.
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, Data, DeriveInput, Error, Fields};
+
+/// the parts of the generated code that change between layouts.
+struct Layout {
+    /// the name of the trait and the prefix of the padded struct.
+    name: &'static str,
+    /// the smallest alignment a struct can have.
+    min_align: usize,
+    /// std430 is an unsafe trait with extra items for arrays and reading back bytes.
+    std430: bool,
+}
+
+/// implements `glium_types::layout::Std140` and creates a padded copy of the struct that can be
+/// used as a glium uniform block.
+#[proc_macro_derive(Std140)]
+pub fn derive_std140(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    derive_layout(input, Layout { name: "Std140", min_align: 16, std430: false })
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// implements `glium_types::layout::Std430` and creates a padded copy of the struct that can be
+/// used in a glium shader storage buffer.
+#[proc_macro_derive(Std430)]
+pub fn derive_std430(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    derive_layout(input, Layout { name: "Std430", min_align: 1, std430: true })
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn derive_layout(input: DeriveInput, layout: Layout) -> Result<TokenStream2, Error> {
+    let name = &input.ident;
+    let vis = &input.vis;
+    if !input.generics.params.is_empty() {
+        return Err(Error::new_spanned(&input.generics, format!("{} can not be derived for generic structs", layout.name)));
+    }
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) if !fields.named.is_empty() => &fields.named,
+            _ => return Err(Error::new_spanned(name, format!("{} needs a struct with named fields", layout.name)))
+        },
+        _ => return Err(Error::new_spanned(name, format!("{} can only be derived for structs", layout.name)))
+    };
+    let trait_name = format_ident!("{}", layout.name);
+    let lower = layout.name.to_lowercase();
+    let to = format_ident!("to_{}", lower);
+    let from = format_ident!("from_{}", lower);
+    let padded = format_ident!("{}{}", layout.name, name);
+    let min_align = layout.min_align;
+    let module = quote!(::glium_types::layout);
+    let trait_path = quote!(#module::#trait_name);
+
+    let names: Vec<_> = fields.iter().map(|f| f.ident.clone().unwrap()).collect();
+    let name_strings: Vec<_> = names.iter().map(|n| n.to_string()).collect();
+    let types: Vec<_> = fields.iter().map(|f| &f.ty).collect();
+    let visibilities: Vec<_> = fields.iter().map(|f| &f.vis).collect();
+    let paddings: Vec<_> = names.iter().map(|n| format_ident!("_pad_{}", n)).collect();
+    let indices: Vec<_> = (0..fields.len()).collect();
+    let field_layouts = quote!(&[#((
+        <#types as #trait_path>::ALIGN, ::core::mem::size_of::<<#types as #trait_path>::Padded>()
+    )),*]);
+    let (unsafety, std430_items) = if layout.std430 {
+        (quote!(unsafe), quote! {
+            const END: usize = #module::struct_end(#field_layouts);
+            type ArrayElement = #padded;
+            fn to_std430_element(&self) -> #padded { self.to_std430() }
+            fn from_std430_element(element: &#padded) -> Self { Self::from_std430(element) }
+        })
+    } else {
+        (quote!(), quote!())
+    };
+    let doc = format!("a copy of `{name}` with {lower} padding. created with `{name}::{to}`.");
+
+    Ok(quote! {
+        #[doc = #doc]
+        #[derive(Clone, Copy)]
+        #[repr(C)]
+        #vis struct #padded {
+            #(
+                #paddings: [u8; #module::field_padding(#field_layouts, #indices)],
+                #visibilities #names: <#types as #trait_path>::Padded,
+            )*
+            _pad_end: [u8; #module::end_padding(#field_layouts, #min_align)],
+        }
+        // makes sure rust didn't add any padding of its own
+        const _: () = {
+            #( assert!(::core::mem::offset_of!(#padded, #names) == #module::field_offset(#field_layouts, #indices)); )*
+            assert!(::core::mem::size_of::<#padded>() == #module::struct_size(#field_layouts, #min_align));
+        };
+        #unsafety impl #trait_path for #name {
+            const ALIGN: usize = #module::struct_align(#field_layouts, #min_align);
+            const OFFSETS: &'static [(&'static str, usize)] = &[
+                #( (#name_strings, #module::field_offset(#field_layouts, #indices)) ),*
+            ];
+            type Padded = #padded;
+            #std430_items
+            fn #to(&self) -> #padded {
+                #padded {
+                    #( #paddings: #module::padding(), #names: <#types as #trait_path>::#to(&self.#names), )*
+                    _pad_end: #module::padding(),
+                }
+            }
+            fn #from(padded: &#padded) -> Self {
+                Self { #( #names: <#types as #trait_path>::#from(&padded.#names), )* }
+            }
+            fn glsl_type() -> ::std::string::String { ::std::string::String::from(stringify!(#name)) }
+            fn glsl_members() -> ::std::string::String {
+                [#( #module::glsl_member(
+                    &<#types as #trait_path>::glsl_type(), #name_strings, &<#types as #trait_path>::glsl_array_suffix()
+                ) ),*].concat()
+            }
+            fn glsl_structs(definitions: &mut ::std::vec::Vec<::std::string::String>) {
+                #( <#types as #trait_path>::glsl_structs(definitions); )*
+                let definition = #module::glsl_struct(stringify!(#name), &Self::glsl_members());
+                if !definitions.contains(&definition) { definitions.push(definition) }
+            }
+        }
+        impl ::glium_types::glium::uniforms::UniformBlock for #padded {
+            fn matches(layout: &::glium_types::glium::program::BlockLayout, base_offset: usize)
+                -> ::core::result::Result<(), ::glium_types::glium::uniforms::LayoutMismatchError>
+            {
+                #module::struct_matches(layout, base_offset, &[#((
+                    #name_strings, ::core::mem::offset_of!(#padded, #names),
+                    <<#types as #trait_path>::Padded as ::glium_types::glium::uniforms::UniformBlock>::matches
+                )),*], <Self as ::glium_types::glium::uniforms::UniformBlock>::build_layout)
+            }
+            fn build_layout(base_offset: usize) -> ::glium_types::glium::program::BlockLayout {
+                ::glium_types::glium::program::BlockLayout::Struct { members: ::std::vec![#((
+                    ::std::string::String::from(#name_strings),
+                    <<#types as #trait_path>::Padded as ::glium_types::glium::uniforms::UniformBlock>::build_layout(
+                        base_offset + ::core::mem::offset_of!(#padded, #names)
+                    )
+                )),*] }
+            }
+        }
+    })
+}
@@ -0,0 +1,162 @@
+//! approximate equality for the float vectors, matrices and quaternions. every component has to be
+//! approximately equal for the values to be approximately equal. the comparisons work the same as
+//! the `approx` crate, which is implemented when the `approx` feature is enabled.
+use crate::{vectors::*, matrices::*, quaternions::*};
+
+/// the per component comparisons.
+trait Scalar: Sized {
+    fn abs_diff_eq(self, other: Self, epsilon: Self) -> bool;
+    fn relative_eq(self, other: Self, epsilon: Self, max_relative: Self) -> bool;
+    fn ulps_eq(self, other: Self, epsilon: Self, max_ulps: u32) -> bool;
+}
+macro_rules! impl_scalar {
+    ($float:ident) => {
+        impl Scalar for $float {
+            fn abs_diff_eq(self, other: Self, epsilon: Self) -> bool {
+                self == other || (self - other).abs() <= epsilon
+            }
+            fn relative_eq(self, other: Self, epsilon: Self, max_relative: Self) -> bool {
+                if self == other { return true; }
+                if self.is_infinite() || other.is_infinite() { return false; }
+                let difference = (self - other).abs();
+                if difference <= epsilon { return true; }
+                difference <= self.abs().max(other.abs()) * max_relative
+            }
+            fn ulps_eq(self, other: Self, epsilon: Self, max_ulps: u32) -> bool {
+                if self.abs_diff_eq(other, epsilon) { return true; }
+                if self.is_sign_negative() != other.is_sign_negative() { return false; }
+                self.to_bits().abs_diff(other.to_bits()) <= max_ulps as _
+            }
+        }
+    };
+}
+impl_scalar!(f32);
+impl_scalar!(f64);
+
+macro_rules! impl_approx_eq {
+    ($ty:ident, $float:ident; $v:ident => $components:expr) => {
+        impl $ty {
+            fn approx_components(self, other: Self, eq: impl Fn($float, $float) -> bool) -> bool {
+                let components = |$v: Self| $components;
+                components(self).into_iter().zip(components(other)).all(|(a, b)| eq(a, b))
+            }
+            /// true if every component is at most `epsilon` away from the component in `other`.
+            pub fn abs_diff_eq(self, other: Self, epsilon: $float) -> bool {
+                self.approx_components(other, |a, b| a.abs_diff_eq(b, epsilon))
+            }
+            /// true if every component is at most `epsilon` away from the component in `other`,
+            /// or if their difference is at most `max_relative` times the larger of the 2.
+            pub fn relative_eq(self, other: Self, epsilon: $float, max_relative: $float) -> bool {
+                self.approx_components(other, |a, b| a.relative_eq(b, epsilon, max_relative))
+            }
+            /// true if every component is at most `epsilon` away from the component in `other`,
+            /// or if there are at most `max_ulps` floats between them.
+            pub fn ulps_eq(self, other: Self, epsilon: $float, max_ulps: u32) -> bool {
+                self.approx_components(other, |a, b| a.ulps_eq(b, epsilon, max_ulps))
+            }
+        }
+        #[cfg(feature = "approx")]
+        impl approx::AbsDiffEq for $ty {
+            type Epsilon = $float;
+            fn default_epsilon() -> $float { $float::EPSILON }
+            fn abs_diff_eq(&self, other: &Self, epsilon: $float) -> bool {
+                $ty::abs_diff_eq(*self, *other, epsilon)
+            }
+        }
+        #[cfg(feature = "approx")]
+        impl approx::RelativeEq for $ty {
+            fn default_max_relative() -> $float { $float::EPSILON }
+            fn relative_eq(&self, other: &Self, epsilon: $float, max_relative: $float) -> bool {
+                $ty::relative_eq(*self, *other, epsilon, max_relative)
+            }
+        }
+        #[cfg(feature = "approx")]
+        impl approx::UlpsEq for $ty {
+            fn default_max_ulps() -> u32 { 4 }
+            fn ulps_eq(&self, other: &Self, epsilon: $float, max_ulps: u32) -> bool {
+                $ty::ulps_eq(*self, *other, epsilon, max_ulps)
+            }
+        }
+    };
+}
+impl_approx_eq!(Vec2, f32; v => [v.x, v.y]);
+impl_approx_eq!(Vec3, f32; v => [v.x, v.y, v.z]);
+impl_approx_eq!(Vec4, f32; v => [v.x, v.y, v.z, v.w]);
+impl_approx_eq!(DVec2, f64; v => [v.x, v.y]);
+impl_approx_eq!(DVec3, f64; v => [v.x, v.y, v.z]);
+impl_approx_eq!(DVec4, f64; v => [v.x, v.y, v.z, v.w]);
+impl_approx_eq!(Mat2, f32; m => m.into_column_major_array().concat());
+impl_approx_eq!(Mat3, f32; m => m.into_column_major_array().concat());
+impl_approx_eq!(Mat4, f32; m => m.into_column_major_array().concat());
+impl_approx_eq!(DMat2, f64; m => m.into_column_major_array().concat());
+impl_approx_eq!(DMat3, f64; m => m.into_column_major_array().concat());
+impl_approx_eq!(DMat4, f64; m => m.into_column_major_array().concat());
+impl_approx_eq!(Quat, f32; q => [q.r, q.i, q.j, q.k]);
+impl_approx_eq!(DQuat, f64; q => [q.r, q.i, q.j, q.k]);
+
+/// asserts that 2 vectors, matrices or quaternions are approximately equal, printing both if they
+/// are not. with no tolerances given the components have to be within `1e-5` of each other or of
+/// each others size. `epsilon` on its own uses `abs_diff_eq`, adding `max_relative` uses
+/// `relative_eq` and adding `max_ulps` uses `ulps_eq`.
+/// ```
+/// # use glium_types::prelude::*;
+/// let a = Mat4::from_scale(vec3(1.1, 2.0, 3.9));
+/// assert_approx_eq!(a.inverse() * a, Mat4::IDENTITY);
+/// assert_approx_eq!(vec2(1.0, 2.0), vec2(1.05, 2.0), epsilon = 0.1);
+/// assert_approx_eq!(Quat::IDENTITY, Quat::from_x_rot(0.0), epsilon = 0.0, max_ulps = 4);
+/// ```
+#[macro_export]
+macro_rules! assert_approx_eq {
+    (@check $left:expr, $right:expr, |$a:ident, $b:ident| $eq:expr) => {{
+        // taken by value so the inherent methods are used over the `approx` traits
+        let ($a, $b) = ($left, $right);
+        if !$eq {
+            panic!("assertion `left ≈ right` failed\n  left: {:?}\n right: {:?}", $a, $b);
+        }
+    }};
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::assert_approx_eq!($left, $right, epsilon = 1e-5, max_relative = 1e-5)
+    };
+    ($left:expr, $right:expr, epsilon = $epsilon:expr $(,)?) => {
+        $crate::assert_approx_eq!(@check $left, $right, |a, b| a.abs_diff_eq(b, $epsilon))
+    };
+    ($left:expr, $right:expr, epsilon = $epsilon:expr, max_relative = $max_relative:expr $(,)?) => {
+        $crate::assert_approx_eq!(@check $left, $right,
+            |a, b| a.relative_eq(b, $epsilon, $max_relative))
+    };
+    ($left:expr, $right:expr, epsilon = $epsilon:expr, max_ulps = $max_ulps:expr $(,)?) => {
+        $crate::assert_approx_eq!(@check $left, $right, |a, b| a.ulps_eq(b, $epsilon, $max_ulps))
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn tolerances() {
+        let a = vec3(1.0, 100.0, -0.5);
+        assert!(a.abs_diff_eq(a + Vec3::splat(1e-3), 1e-2));
+        assert!(!a.abs_diff_eq(a + Vec3::splat(1e-1), 1e-2));
+        assert!(a.relative_eq(vec3(1.0, 100.001, -0.5), 0.0, 1e-4));
+        assert!(!a.relative_eq(vec3(1.0, 100.1, -0.5), 0.0, 1e-4));
+        let next = f32::from_bits(1.0f32.to_bits() + 2);
+        assert!(Vec2::ONE.ulps_eq(vec2(1.0, next), 0.0, 2));
+        assert!(!Vec2::ONE.ulps_eq(vec2(1.0, next), 0.0, 1));
+        assert!(!vec2(1e-40, 0.0).ulps_eq(vec2(-1e-40, 0.0), 0.0, u32::MAX));
+        assert!(!dvec2(f64::INFINITY, 0.0).relative_eq(dvec2(f64::MAX, 0.0), 0.0, 1.0));
+    }
+    #[test]
+    #[should_panic]
+    fn assert_fails() {
+        assert_approx_eq!(DMat2::IDENTITY, DMat2::IDENTITY * 1.001);
+    }
+    #[cfg(feature = "approx")]
+    #[test]
+    fn approx_traits() {
+        use approx::{AbsDiffEq, assert_relative_eq, assert_ulps_eq};
+        assert_relative_eq!(Quat::from_x_rot(1.0) * Quat::from_x_rot(-1.0), Quat::IDENTITY);
+        assert_ulps_eq!(dvec3(0.1, 0.2, 0.3) * 3.0, dvec3(0.3, 0.6, 0.9));
+        assert!(Vec2::default_epsilon() == f32::EPSILON);
+        assert_approx_eq!(vec2(0.1, 0.2), vec2(0.1, 0.2));
+    }
+}
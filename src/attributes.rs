@@ -0,0 +1,63 @@
+//! lets vectors and matrices be used as fields in glium vertices. this relies on the `#[repr(C)]`
+//! layout of the types, so a `Vec3` is stored the same as `[f32; 3]` and a `Mat4` is stored as 4
+//! columns. the normalized integer vectors also need `normalize(true)` in `implement_vertex!`.
+use glium::vertex::{Attribute, AttributeType};
+use crate::{vectors::*, matrices::*, colour::*};
+
+macro_rules! impl_attributes {
+    ($($ty:ident => $attribute:ident),* $(,)?) => {
+        $(
+            unsafe impl Attribute for $ty {
+                const TYPE: AttributeType = AttributeType::$attribute;
+            }
+        )*
+    };
+}
+impl_attributes!(
+    Vec2 => F32F32, Vec3 => F32F32F32, Vec4 => F32F32F32F32,
+    DVec2 => F64F64, DVec3 => F64F64F64, DVec4 => F64F64F64F64,
+    IVec2 => I32I32, IVec3 => I32I32I32, IVec4 => I32I32I32I32,
+    UVec2 => U32U32, UVec3 => U32U32U32, UVec4 => U32U32U32U32,
+    DIVec2 => I64I64, DIVec3 => I64I64I64, DIVec4 => I64I64I64I64,
+    DUVec2 => U64U64, DUVec3 => U64U64U64, DUVec4 => U64U64U64U64,
+    Mat2 => F32x2x2, Mat3 => F32x3x3, Mat4 => F32x4x4,
+    DMat2 => F64x2x2, DMat3 => F64x3x3, DMat4 => F64x4x4,
+    Unorm8x2 => U8U8, Unorm8x3 => U8U8U8, Unorm8x4 => U8U8U8U8,
+    Snorm8x2 => I8I8, Snorm8x3 => I8I8I8, Snorm8x4 => I8I8I8I8,
+    Unorm16x2 => U16U16, Unorm16x3 => U16U16U16, Unorm16x4 => U16U16U16U16,
+    Snorm16x2 => I16I16, Snorm16x3 => I16I16I16, Snorm16x4 => I16I16I16I16,
+    Int2101010Rev => I2I10I10I10Reversed, Rgb9e5 => U32, Rgbe => U8U8U8U8,
+    Rgb => F32F32F32, Rgba => F32F32F32F32, Srgb => F32F32F32, Srgba => F32F32F32F32,
+);
+#[cfg(feature = "half")]
+impl_attributes!(HVec2 => F16F16, HVec3 => F16F16F16, HVec4 => F16F16F16F16);
+
+#[cfg(test)]
+mod tests {
+    use glium::{implement_vertex, Vertex};
+    use super::*;
+    #[derive(Clone, Copy)]
+    struct Instance {
+        position: Vec3,
+        uv: Vec2,
+        id: UVec2,
+        model: Mat4,
+    }
+    implement_vertex!(Instance, position, uv, id, model);
+    #[test]
+    fn vertex_format() {
+        let format = Instance::build_bindings();
+        let expected = [
+            ("position", std::mem::offset_of!(Instance, position), AttributeType::F32F32F32),
+            ("uv", std::mem::offset_of!(Instance, uv), AttributeType::F32F32),
+            ("id", std::mem::offset_of!(Instance, id), AttributeType::U32U32),
+            ("model", std::mem::offset_of!(Instance, model), AttributeType::F32x4x4),
+        ];
+        assert_eq!(format.len(), expected.len());
+        for ((name, offset, _, ty, _), expected) in format.iter().zip(expected) {
+            assert_eq!((name.as_ref(), *offset, *ty), expected);
+        }
+        assert_eq!(AttributeType::F32x4x4.get_size_bytes(), std::mem::size_of::<Mat4>());
+        assert_eq!(AttributeType::I64I64I64.get_size_bytes(), std::mem::size_of::<DIVec3>());
+    }
+}
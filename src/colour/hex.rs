@@ -0,0 +1,104 @@
+//! `#rrggbbaa` hex codes. hex codes are always srgb encoded, so the linear colours convert when
+//! parsing and formatting.
+use std::{fmt, str::FromStr};
+use super::*;
+
+/// why a hex code couldn't be parsed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum HexError {
+    /// there weren't 3, 4, 6 or 8 digits, or there was alpha for a colour without it.
+    Length(usize),
+    /// a character wasn't a hex digit. `index` counts characters in the string that was parsed,
+    /// including the `#`.
+    InvalidDigit { index: usize, found: char },
+}
+impl fmt::Display for HexError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Length(length) => write!(f, "a hex colour can't have {length} digits"),
+            Self::InvalidDigit { index, found } => write!(f, "`{found}` at {index} is not a hex digit"),
+        }
+    }
+}
+impl std::error::Error for HexError {}
+
+/// parses `#rgb`, `#rgba`, `#rrggbb` or `#rrggbbaa`, with or without the `#`.
+fn parse(hex: &str, alpha: bool) -> Result<Srgba, HexError> {
+    let digits = hex.trim().strip_prefix('#').unwrap_or(hex.trim());
+    // the number of characters before the digits, so errors point into `hex`
+    let skipped = hex[..hex.len() - hex.trim_start().len()].chars().count() + hex.trim().starts_with('#') as usize;
+    let values = digits.chars().enumerate()
+        .map(|(i, c)| c.to_digit(16).map(|d| d as u8).ok_or(HexError::InvalidDigit { index: skipped + i, found: c }))
+        .collect::<Result<Vec<_>, _>>()?;
+    let bytes: Vec<_> = match values.len() {
+        3 | 4 => values.iter().map(|d| d * 17).collect(),
+        6 | 8 => values.chunks(2).map(|d| d[0] * 16 + d[1]).collect(),
+        length => return Err(HexError::Length(length))
+    };
+    if !alpha && bytes.len() == 4 { return Err(HexError::Length(values.len())) }
+    let a = bytes.get(3).copied().unwrap_or(255);
+    Ok(Vec4::from(Unorm8x4::new(bytes[0], bytes[1], bytes[2], a)).into())
+}
+/// rounds to the nearest 8 bit value.
+fn format(colour: Srgba, alpha: bool) -> String {
+    let Unorm8x4 { x, y, z, w } = Vec4::from(colour).into();
+    if alpha { format!("#{x:02x}{y:02x}{z:02x}{w:02x}") } else { format!("#{x:02x}{y:02x}{z:02x}") }
+}
+
+macro_rules! impl_hex {
+    ($($colour:ident, $alpha:literal, $example:literal, $from_srgba:expr, $to_srgba:expr;)*) => {
+        $(
+            impl $colour {
+                #[doc = concat!("parses a hex code like `", $example, "`, with or without the `#`. short codes have")]
+                /// each digit repeated, so `#f80` is `#ff8800`.
+                pub fn from_hex(hex: &str) -> Result<Self, HexError> { parse(hex, $alpha).map($from_srgba) }
+                #[doc = concat!("formats as a hex code like `", $example, "`, rounding to the nearest 8 bit value.")]
+                pub fn to_hex(self) -> String { format($to_srgba(self), $alpha) }
+            }
+            /// parses a hex code.
+            impl FromStr for $colour {
+                type Err = HexError;
+                fn from_str(s: &str) -> Result<Self, HexError> { Self::from_hex(s) }
+            }
+        )*
+    };
+}
+impl_hex!(
+    Rgb, false, "#rrggbb", |c: Srgba| Rgb::from(c.rgb()), |c: Rgb| Srgb::from(c).with_alpha(1.0);
+    Rgba, true, "#rrggbbaa", Rgba::from, Srgba::from;
+    Srgb, false, "#rrggbb", Srgba::rgb, |c: Srgb| c.with_alpha(1.0);
+    Srgba, true, "#rrggbbaa", |c| c, |c| c;
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn parsing() {
+        assert_eq!(Srgb::from_hex("#ff8000"), Ok(Srgb::new(1.0, 128.0 / 255.0, 0.0)));
+        assert_eq!("f80".parse(), Ok(Srgb::new(1.0, 136.0 / 255.0, 0.0)));
+        assert_eq!(" #00000080 ".parse(), Ok(Srgba::new(0.0, 0.0, 0.0, 128.0 / 255.0)));
+        assert_eq!(Srgba::from_hex("#FFF"), Ok(Srgba::WHITE));
+        assert_eq!(Rgba::from_hex("#0f08"), Ok(Rgba::new(0.0, 1.0, 0.0, 136.0 / 255.0)));
+        assert_eq!(Rgb::from_hex("#808080"), Ok(Rgb::from(Srgb::from_hex("#808080").unwrap())));
+        assert_eq!(Srgb::from_hex("#ff800080"), Err(HexError::Length(8)));
+        assert_eq!(Srgb::from_hex("#ff80"), Err(HexError::Length(4)));
+        assert_eq!(Srgba::from_hex("#ff80000"), Err(HexError::Length(7)));
+        assert_eq!(Srgba::from_hex(""), Err(HexError::Length(0)));
+        assert_eq!(Srgba::from_hex("#ff8g00"), Err(HexError::InvalidDigit { index: 4, found: 'g' }));
+        assert_eq!(Srgb::from_hex(" ff8g00"), Err(HexError::InvalidDigit { index: 4, found: 'g' }));
+        assert_eq!(Srgb::from_hex("#fx0").unwrap_err().to_string(), "`x` at 2 is not a hex digit");
+    }
+    #[test]
+    fn formatting() {
+        assert_eq!(Srgb::new(1.0, 0.5, 0.0).to_hex(), "#ff8000");
+        assert_eq!(Srgba::new(1.0, 0.5, 0.0, 0.25).to_hex(), "#ff800040");
+        assert_eq!(Srgba::new(2.0, -1.0, 0.0, 1.0).to_hex(), "#ff0000ff");
+        assert_eq!(Rgb::from(Srgb::new(0.5, 0.5, 0.5)).to_hex(), "#808080");
+        // every 8 bit value round trips through linear
+        for i in 0..=255u32 {
+            let hex = format!("#{i:02x}{:02x}{:02x}{i:02x}", 255 - i, i / 2);
+            assert_eq!(Rgba::from_hex(&hex).unwrap().to_hex(), hex);
+        }
+    }
+}
@@ -0,0 +1,285 @@
+//! colours with a known colour space. `Rgb` and `Rgba` are linear, which is what lighting and
+//! blending maths expects and what glium writes to an srgb framebuffer. `Srgb` and `Srgba` are
+//! gamma encoded the same as hex codes, images and colour pickers. converting between them uses
+//! the exact srgb transfer functions, and components outside of 0.0 to 1.0 are kept so wide
+//! gamut colours still convert back.
+//! ```
+//! use glium_types::colour::*;
+//! let orange = Srgb::from_hex("#ff8000").unwrap();
+//! let linear = Rgb::from(orange);
+//! assert!((linear.g - 0.2158605).abs() < 1e-6);
+//! assert_eq!(Srgb::from(linear).to_hex(), "#ff8000");
+//! let hsv = Hsv::from(orange);
+//! assert!((hsv.h - 30.1176).abs() < 1e-3 && hsv.s == 1.0 && hsv.v == 1.0);
+//! // colours can be used as uniforms or vertex colours
+//! let tint = Rgba::from_kelvin(3000.0).premultiply();
+//! let colour = glium_types::vert_types::VertexColour::from(tint);
+//! ```
+use glium::uniforms::{AsUniformValue, UniformValue};
+use crate::{vectors::*, vert_types::VertexColour};
+mod hex;
+mod models;
+pub use hex::HexError;
+pub use models::{Hsv, Hsl, Oklab};
+
+/// converts an srgb encoded component to linear.
+pub fn srgb_to_linear(c: f32) -> f32 {
+    let a = c.abs();
+    let linear = if a <= 0.04045 { a / 12.92 } else { ((a + 0.055) / 1.055).powf(2.4) };
+    linear.copysign(c)
+}
+/// converts a linear component to srgb encoded.
+pub fn linear_to_srgb(c: f32) -> f32 {
+    let a = c.abs();
+    let srgb = if a <= 0.0031308 { a * 12.92 } else { 1.055 * a.powf(1.0 / 2.4) - 0.055 };
+    srgb.copysign(c)
+}
+
+macro_rules! impl_colour {
+    ($($colour:ident, $vec:ident, $uniform:ident { $($c:ident $v:ident)* };)*) => {
+        $(
+            impl $colour {
+                pub const fn new($($c: f32),*) -> Self { Self { $($c),* } }
+            }
+            impl From<$vec> for $colour {
+                fn from(value: $vec) -> Self { Self { $($c: value.$v),* } }
+            }
+            impl From<$colour> for $vec {
+                fn from(value: $colour) -> Self { Self { $($v: value.$c),* } }
+            }
+            /// sends the components as they are, so srgb colours stay encoded.
+            impl AsUniformValue for $colour {
+                fn as_uniform_value(&self) -> UniformValue<'_> { UniformValue::$uniform([$(self.$c),*]) }
+            }
+        )*
+    };
+}
+impl_colour!(
+    Rgb, Vec3, Vec3 { r x g y b z }; Rgba, Vec4, Vec4 { r x g y b z a w };
+    Srgb, Vec3, Vec3 { r x g y b z }; Srgba, Vec4, Vec4 { r x g y b z a w };
+);
+macro_rules! impl_colour_consts {
+    ($($rgb:ident, $rgba:ident;)*) => {
+        $(
+            impl $rgb {
+                pub const BLACK: Self = Self::new(0.0, 0.0, 0.0);
+                pub const WHITE: Self = Self::new(1.0, 1.0, 1.0);
+                /// adds an alpha component.
+                pub const fn with_alpha(self, a: f32) -> $rgba { $rgba::new(self.r, self.g, self.b, a) }
+            }
+            impl $rgba {
+                pub const BLACK: Self = Self::new(0.0, 0.0, 0.0, 1.0);
+                pub const WHITE: Self = Self::new(1.0, 1.0, 1.0, 1.0);
+                pub const TRANSPARENT: Self = Self::new(0.0, 0.0, 0.0, 0.0);
+                /// the colour without alpha.
+                pub const fn rgb(self) -> $rgb { $rgb::new(self.r, self.g, self.b) }
+            }
+            /// alpha is 1.0.
+            impl From<$rgb> for $rgba {
+                fn from(value: $rgb) -> Self { value.with_alpha(1.0) }
+            }
+        )*
+    };
+}
+impl_colour_consts!(Rgb, Rgba; Srgb, Srgba;);
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+/// a linear rgb colour.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[repr(C)]
+pub struct Rgb {
+    pub r: f32,
+    pub g: f32,
+    pub b: f32
+}
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+/// a linear rgb colour with straight alpha, unless it has been premultiplied.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[repr(C)]
+pub struct Rgba {
+    pub r: f32,
+    pub g: f32,
+    pub b: f32,
+    pub a: f32
+}
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+/// an srgb encoded colour.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[repr(C)]
+pub struct Srgb {
+    pub r: f32,
+    pub g: f32,
+    pub b: f32
+}
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+/// an srgb encoded colour with linear alpha, the same as css and most image formats.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[repr(C)]
+pub struct Srgba {
+    pub r: f32,
+    pub g: f32,
+    pub b: f32,
+    pub a: f32
+}
+
+impl Rgb {
+    /// the brightness of the colour as seen by people, using the rec. 709 weights.
+    pub fn luminance(self) -> f32 { 0.2126 * self.r + 0.7152 * self.g + 0.0722 * self.b }
+    /// the colour of a black body at `kelvin` degrees, scaled so the largest component is 1.0.
+    /// candle light is about 1900K, a light bulb 2700K, daylight 6500K and a blue sky 10000K.
+    /// temperatures are clamped from 1667K to 25000K, and the colour is within about 0.01 of the
+    /// planckian locus.
+    pub fn from_kelvin(kelvin: f32) -> Self {
+        let t = kelvin.clamp(1667.0, 25000.0) as f64;
+        // cie 1931 chromaticity of the planckian locus, from kim et al.'s cubic spline
+        let x = if t <= 4000.0 {
+            -0.2661239e9 / (t * t * t) - 0.2343589e6 / (t * t) + 0.8776956e3 / t + 0.179910
+        } else {
+            -3.0258469e9 / (t * t * t) + 2.1070379e6 / (t * t) + 0.2226347e3 / t + 0.240390
+        };
+        let y = if t <= 2222.0 {
+            -1.1063814 * x * x * x - 1.34811020 * x * x + 2.18555832 * x - 0.20219683
+        } else if t <= 4000.0 {
+            -0.9549476 * x * x * x - 1.37418593 * x * x + 2.09137015 * x - 0.16748867
+        } else {
+            3.0817580 * x * x * x - 5.87338670 * x * x + 3.75112997 * x - 0.37001483
+        };
+        let [cx, cy, cz] = [x / y, 1.0, (1.0 - x - y) / y];
+        let rgb = dvec3(
+            3.2404542 * cx - 1.5371385 * cy - 0.4985314 * cz,
+            -0.9692660 * cx + 1.8760108 * cy + 0.0415560 * cz,
+            0.0556434 * cx - 0.2040259 * cy + 1.0572252 * cz
+        ).map(|c| c.max(0.0));
+        let rgb = rgb / rgb.max_element();
+        Self::new(rgb.x as f32, rgb.y as f32, rgb.z as f32)
+    }
+}
+impl Rgba {
+    /// the colour of a black body at `kelvin` degrees with an alpha of 1.0. see `Rgb::from_kelvin`.
+    pub fn from_kelvin(kelvin: f32) -> Self { Rgb::from_kelvin(kelvin).into() }
+    /// multiplies the colour by alpha. premultiplied colours blend correctly with
+    /// `glium::Blend { color: BlendingFunction::Addition { source: LinearBlendingFactor::One, .. }, .. }`
+    /// and don't bleed the colour of transparent pixels when filtered.
+    pub fn premultiply(self) -> Self { Self::new(self.r * self.a, self.g * self.a, self.b * self.a, self.a) }
+    /// divides a premultiplied colour by alpha. fully transparent colours become `Rgba::TRANSPARENT`.
+    pub fn unpremultiply(self) -> Self {
+        if self.a == 0.0 { return Self::TRANSPARENT }
+        Self::new(self.r / self.a, self.g / self.a, self.b / self.a, self.a)
+    }
+    /// draws `self` on top of `below`. both colours must be premultiplied.
+    pub fn over(self, below: Self) -> Self {
+        let (s, b) = (Vec4::from(self), Vec4::from(below));
+        (s + b * (1.0 - self.a)).into()
+    }
+}
+
+impl From<Srgb> for Rgb {
+    fn from(value: Srgb) -> Self {
+        Self::new(srgb_to_linear(value.r), srgb_to_linear(value.g), srgb_to_linear(value.b))
+    }
+}
+impl From<Rgb> for Srgb {
+    fn from(value: Rgb) -> Self {
+        Self::new(linear_to_srgb(value.r), linear_to_srgb(value.g), linear_to_srgb(value.b))
+    }
+}
+/// alpha is already linear, so it is kept the same.
+impl From<Srgba> for Rgba {
+    fn from(value: Srgba) -> Self { Rgb::from(value.rgb()).with_alpha(value.a) }
+}
+/// alpha is already linear, so it is kept the same.
+impl From<Rgba> for Srgba {
+    fn from(value: Rgba) -> Self { Srgb::from(value.rgb()).with_alpha(value.a) }
+}
+
+impl From<Rgba> for VertexColour {
+    fn from(value: Rgba) -> Self { Vec4::from(value).into() }
+}
+impl From<Rgb> for VertexColour {
+    fn from(value: Rgb) -> Self { Rgba::from(value).into() }
+}
+/// converted to linear, since that is what the supplied shaders and srgb framebuffers expect.
+impl From<Srgba> for VertexColour {
+    fn from(value: Srgba) -> Self { Rgba::from(value).into() }
+}
+/// converted to linear, since that is what the supplied shaders and srgb framebuffers expect.
+impl From<Srgb> for VertexColour {
+    fn from(value: Srgb) -> Self { Rgba::from(value.with_alpha(1.0)).into() }
+}
+impl From<VertexColour> for Rgba {
+    fn from(value: VertexColour) -> Self {
+        let (r, g, b, a) = value.colour;
+        Self::new(r, g, b, a)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use glium::{implement_vertex, Vertex, vertex::AttributeType};
+    use super::*;
+    #[test]
+    fn transfer_functions() {
+        // every 8 bit value survives a round trip, and the curve meets at the threshold
+        for i in 0..=255 {
+            let c = i as f32 / 255.0;
+            assert!((linear_to_srgb(srgb_to_linear(c)) - c).abs() < 1e-6, "{c}");
+        }
+        assert!((srgb_to_linear(0.04045) - 0.04045 / 12.92).abs() < 1e-9);
+        assert!((srgb_to_linear(0.5) - 0.21404114).abs() < 1e-7);
+        assert!((linear_to_srgb(0.5) - 0.7353569).abs() < 1e-6);
+        assert!(srgb_to_linear(1.0) == 1.0 && srgb_to_linear(0.0) == 0.0);
+        // wide gamut values are mirrored, so they still round trip
+        assert!((linear_to_srgb(srgb_to_linear(-0.5)) + 0.5).abs() < 1e-6);
+        assert!((linear_to_srgb(srgb_to_linear(1.5)) - 1.5).abs() < 1e-6);
+        let colour = Srgba::new(1.0, 0.5, 0.0, 0.25);
+        assert!(Rgba::from(colour).a == 0.25 && Rgba::from(colour).r == 1.0);
+    }
+    #[test]
+    fn premultiplied_alpha() {
+        let colour = Rgba::new(1.0, 0.5, 0.2, 0.5);
+        assert!(colour.premultiply() == Rgba::new(0.5, 0.25, 0.1, 0.5));
+        assert!(colour.premultiply().unpremultiply() == colour);
+        assert!(Rgba::new(1.0, 1.0, 1.0, 0.0).premultiply().unpremultiply() == Rgba::TRANSPARENT);
+        let blended = colour.premultiply().over(Rgba::new(0.0, 0.0, 1.0, 1.0));
+        assert!(blended == Rgba::new(0.5, 0.25, 0.6, 1.0));
+        assert!(Rgba::TRANSPARENT.over(Rgba::WHITE) == Rgba::WHITE);
+    }
+    #[test]
+    fn kelvin() {
+        // daylight is close to white
+        let daylight = Rgb::from_kelvin(6500.0);
+        assert!(daylight.r > 0.9 && daylight.g > 0.9 && daylight.b > 0.9, "{daylight:?}");
+        let warm = Rgb::from_kelvin(2700.0);
+        assert!(warm.r == 1.0 && warm.g < 0.6 && warm.b < warm.g, "{warm:?}");
+        let cold = Rgb::from_kelvin(15000.0);
+        assert!(cold.b == 1.0 && cold.r < cold.g, "{cold:?}");
+        // hotter is always bluer
+        let mut last = f32::INFINITY;
+        for t in (1000..30000).step_by(250) {
+            let c = Rgb::from_kelvin(t as f32);
+            assert!(c.r / c.b <= last, "{t}");
+            last = c.r / c.b;
+        }
+    }
+    #[test]
+    fn uniforms_and_vertices() {
+        #[derive(Clone, Copy)]
+        struct ColouredVertex {
+            position: Vec3,
+            colour: Rgba,
+        }
+        implement_vertex!(ColouredVertex, position, colour);
+        assert!(ColouredVertex::build_bindings()[1].3 == AttributeType::F32F32F32F32);
+        let v = ColouredVertex { position: Vec3::ZERO, colour: Rgba::WHITE };
+        assert!(v.position == Vec3::ZERO && v.colour == Rgba::WHITE);
+        assert!(matches!(Rgb::new(1.0, 0.5, 0.0).as_uniform_value(), UniformValue::Vec3([1.0, 0.5, 0.0])));
+        assert!(matches!(Srgba::new(0.5, 0.5, 0.5, 1.0).as_uniform_value(), UniformValue::Vec4([0.5, 0.5, 0.5, 1.0])));
+        let vertex = VertexColour::from(Srgb::new(0.5, 1.0, 0.0));
+        assert!(vertex.colour == (srgb_to_linear(0.5), 1.0, 0.0, 1.0));
+        assert!(Rgba::from(VertexColour::from(Rgb::WHITE)) == Rgba::WHITE);
+    }
+}
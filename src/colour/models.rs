@@ -0,0 +1,183 @@
+//! colour models that are easier to pick or mix colours in than rgb.
+use super::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+/// hue, saturation and value of an srgb colour. hue is in degrees from 0.0 to 360.0, and saturation
+/// and value are from 0.0 to 1.0.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Hsv {
+    pub h: f32,
+    pub s: f32,
+    pub v: f32
+}
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+/// hue, saturation and lightness of an srgb colour, the same as css `hsl()`. hue is in degrees from
+/// 0.0 to 360.0, and saturation and lightness are from 0.0 to 1.0.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Hsl {
+    pub h: f32,
+    pub s: f32,
+    pub l: f32
+}
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+/// a perceptually uniform colour space, so the same change in any direction looks like the same
+/// change in colour. `l` is lightness from 0.0 to 1.0, and `a` and `b` are how green/red and
+/// blue/yellow it is. mixing colours in oklab avoids the grey middle of mixing in rgb.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Oklab {
+    pub l: f32,
+    pub a: f32,
+    pub b: f32
+}
+impl Hsv {
+    pub const fn new(h: f32, s: f32, v: f32) -> Self { Self { h, s, v } }
+}
+impl Hsl {
+    pub const fn new(h: f32, s: f32, l: f32) -> Self { Self { h, s, l } }
+}
+impl Oklab {
+    pub const fn new(l: f32, a: f32, b: f32) -> Self { Self { l, a, b } }
+    /// linearly interpolates to `other` at `t`.
+    pub fn lerp(self, other: Self, t: f32) -> Self {
+        Self::new(
+            self.l + (other.l - self.l) * t, self.a + (other.a - self.a) * t, self.b + (other.b - self.b) * t
+        )
+    }
+}
+
+/// the hue in degrees, and the largest and smallest component.
+fn hue(c: Srgb) -> (f32, f32, f32) {
+    let max = c.r.max(c.g).max(c.b);
+    let min = c.r.min(c.g).min(c.b);
+    let delta = max - min;
+    let h = if delta == 0.0 { 0.0 }
+        else if max == c.r { 60.0 * ((c.g - c.b) / delta).rem_euclid(6.0) }
+        else if max == c.g { 60.0 * ((c.b - c.r) / delta + 2.0) }
+        else { 60.0 * ((c.r - c.g) / delta + 4.0) };
+    (h, max, min)
+}
+/// the srgb colour with `hue` and `chroma`, without the lightness added.
+fn from_hue(h: f32, chroma: f32) -> Srgb {
+    let h = h.rem_euclid(360.0) / 60.0;
+    let x = chroma * (1.0 - (h % 2.0 - 1.0).abs());
+    match h as u32 {
+        0 => Srgb::new(chroma, x, 0.0),
+        1 => Srgb::new(x, chroma, 0.0),
+        2 => Srgb::new(0.0, chroma, x),
+        3 => Srgb::new(0.0, x, chroma),
+        4 => Srgb::new(x, 0.0, chroma),
+        _ => Srgb::new(chroma, 0.0, x)
+    }
+}
+impl From<Srgb> for Hsv {
+    fn from(value: Srgb) -> Self {
+        let (h, max, min) = hue(value);
+        Self::new(h, if max == 0.0 { 0.0 } else { (max - min) / max }, max)
+    }
+}
+impl From<Hsv> for Srgb {
+    fn from(value: Hsv) -> Self {
+        let chroma = value.v * value.s;
+        let m = value.v - chroma;
+        (Vec3::from(from_hue(value.h, chroma)) + Vec3::splat(m)).into()
+    }
+}
+impl From<Srgb> for Hsl {
+    fn from(value: Srgb) -> Self {
+        let (h, max, min) = hue(value);
+        let l = (max + min) / 2.0;
+        let s = if max == min { 0.0 } else { (max - min) / (1.0 - (2.0 * l - 1.0).abs()) };
+        Self::new(h, s, l)
+    }
+}
+impl From<Hsl> for Srgb {
+    fn from(value: Hsl) -> Self {
+        let chroma = (1.0 - (2.0 * value.l - 1.0).abs()) * value.s;
+        let m = value.l - chroma / 2.0;
+        (Vec3::from(from_hue(value.h, chroma)) + Vec3::splat(m)).into()
+    }
+}
+impl From<Hsv> for Hsl {
+    fn from(value: Hsv) -> Self { Srgb::from(value).into() }
+}
+impl From<Hsl> for Hsv {
+    fn from(value: Hsl) -> Self { Srgb::from(value).into() }
+}
+impl From<Rgb> for Oklab {
+    fn from(c: Rgb) -> Self {
+        // the matrices are from bjorn ottosson's definition of oklab, which has more precision than f32
+        let [r, g, b] = [c.r, c.g, c.b].map(f64::from);
+        let l = (0.4122214708 * r + 0.5363325363 * g + 0.0514459929 * b).cbrt();
+        let m = (0.2119034982 * r + 0.6806995451 * g + 0.1073969566 * b).cbrt();
+        let s = (0.0883024619 * r + 0.2817188376 * g + 0.6299787005 * b).cbrt();
+        Self::new(
+            (0.2104542553 * l + 0.7936177850 * m - 0.0040720468 * s) as f32,
+            (1.9779984951 * l - 2.4285922050 * m + 0.4505937099 * s) as f32,
+            (0.0259040371 * l + 0.7827717662 * m - 0.8086757660 * s) as f32
+        )
+    }
+}
+impl From<Oklab> for Rgb {
+    fn from(c: Oklab) -> Self {
+        let [cl, ca, cb] = [c.l, c.a, c.b].map(f64::from);
+        let l = (cl + 0.3963377774 * ca + 0.2158037573 * cb).powi(3);
+        let m = (cl - 0.1055613458 * ca - 0.0638541728 * cb).powi(3);
+        let s = (cl - 0.0894841775 * ca - 1.2914855480 * cb).powi(3);
+        Self::new(
+            (4.0767416621 * l - 3.3077115913 * m + 0.2309699292 * s) as f32,
+            (-1.2684380046 * l + 2.6097574011 * m - 0.3413193965 * s) as f32,
+            (-0.0041960863 * l - 0.7034186147 * m + 1.7076147010 * s) as f32
+        )
+    }
+}
+impl From<Srgb> for Oklab {
+    fn from(value: Srgb) -> Self { Rgb::from(value).into() }
+}
+impl From<Oklab> for Srgb {
+    fn from(value: Oklab) -> Self { Rgb::from(value).into() }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    fn close(a: Srgb, b: Srgb) -> bool { Vec3::from(a).distance(b.into()) < 1e-5 }
+    #[test]
+    fn hsv_and_hsl() {
+        let colours = [
+            (Srgb::new(1.0, 0.0, 0.0), Hsv::new(0.0, 1.0, 1.0), Hsl::new(0.0, 1.0, 0.5)),
+            (Srgb::new(0.0, 0.5, 0.0), Hsv::new(120.0, 1.0, 0.5), Hsl::new(120.0, 1.0, 0.25)),
+            (Srgb::new(0.5, 0.5, 1.0), Hsv::new(240.0, 0.5, 1.0), Hsl::new(240.0, 1.0, 0.75)),
+            (Srgb::new(1.0, 0.0, 1.0), Hsv::new(300.0, 1.0, 1.0), Hsl::new(300.0, 1.0, 0.5)),
+            (Srgb::new(0.5, 0.5, 0.5), Hsv::new(0.0, 0.0, 0.5), Hsl::new(0.0, 0.0, 0.5)),
+            (Srgb::BLACK, Hsv::new(0.0, 0.0, 0.0), Hsl::new(0.0, 0.0, 0.0)),
+        ];
+        for (srgb, hsv, hsl) in colours {
+            assert!(Hsv::from(srgb) == hsv && Hsl::from(srgb) == hsl, "{srgb:?}");
+            assert!(Srgb::from(hsv) == srgb && Srgb::from(hsl) == srgb, "{srgb:?}");
+        }
+        // every hue round trips, including past 360
+        for i in 0..=720 {
+            let hsv = Hsv::new(i as f32, 0.7, 0.8);
+            let srgb = Srgb::from(hsv);
+            assert!(close(Srgb::from(Hsv::from(srgb)), srgb) && close(Srgb::from(Hsl::from(hsv)), srgb), "{i}");
+            assert!((Hsv::from(srgb).h - (i % 360) as f32).abs() < 1e-3, "{i}");
+        }
+    }
+    #[test]
+    fn oklab() {
+        // reference values from bjorn ottosson's post
+        let white = Oklab::from(Rgb::WHITE);
+        assert!((white.l - 1.0).abs() < 1e-4 && white.a.abs() < 1e-4 && white.b.abs() < 1e-4);
+        let red = Oklab::from(Srgb::new(1.0, 0.0, 0.0));
+        assert!((red.l - 0.627955).abs() < 1e-4 && (red.a - 0.224863).abs() < 1e-4 && (red.b - 0.125846).abs() < 1e-4);
+        // the published matrices aren't exact inverses, but are far closer than an 8 bit step
+        for i in 0..1000 {
+            let srgb = Srgb::new((i % 10) as f32 / 9.0, (i / 10 % 10) as f32 / 9.0, (i / 100) as f32 / 9.0);
+            let error = Vec3::from(Srgb::from(Oklab::from(srgb))).distance(srgb.into());
+            assert!(error < 1e-4, "{srgb:?} {error}");
+        }
+        // the middle of blue and yellow isn't grey
+        let middle = Oklab::from(Srgb::new(0.0, 0.0, 1.0)).lerp(Srgb::new(1.0, 1.0, 0.0).into(), 0.5);
+        assert!(Hsv::from(Srgb::from(middle)).s > 0.05);
+    }
+}
@@ -0,0 +1,139 @@
+//! single curves, which are one segment of a `Curve`.
+use super::*;
+
+/// a bézier curve from `p0` to `p2` that bends towards `p1`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct QuadraticBezier<V> {
+    pub p0: V,
+    pub p1: V,
+    pub p2: V
+}
+/// a bézier curve from `p0` to `p3` that bends towards `p1` then `p2`. it starts heading towards
+/// `p1` and ends heading away from `p2`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CubicBezier<V> {
+    pub p0: V,
+    pub p1: V,
+    pub p2: V,
+    pub p3: V
+}
+/// a cubic curve from `p0` to `p1`, with the derivative `v0` at the start and `v1` at the end.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Hermite<V> {
+    pub p0: V,
+    pub v0: V,
+    pub p1: V,
+    pub v1: V
+}
+
+impl<V: CurveVector> QuadraticBezier<V> {
+    pub const fn new(p0: V, p1: V, p2: V) -> Self { Self { p0, p1, p2 } }
+    /// splits the curve at `t` into the curve before and after it.
+    pub fn split(self, t: V::Scalar) -> (Self, Self) {
+        let lerp = |a: V, b: V| a + (b - a) * t;
+        let (a, b) = (lerp(self.p0, self.p1), lerp(self.p1, self.p2));
+        let c = lerp(a, b);
+        (Self::new(self.p0, a, c), Self::new(c, b, self.p2))
+    }
+}
+impl<V: CurveVector> CubicBezier<V> {
+    pub const fn new(p0: V, p1: V, p2: V, p3: V) -> Self { Self { p0, p1, p2, p3 } }
+    /// the point at `t`. unlike the other curves `t` isn't clamped.
+    pub fn point(&self, t: V::Scalar) -> V {
+        let u = of::<V>(1.0) - t;
+        let three = of::<V>(3.0);
+        self.p0 * (u * u * u) + self.p1 * (three * u * u * t) + self.p2 * (three * u * t * t) + self.p3 * (t * t * t)
+    }
+    /// the derivative at `t`.
+    pub fn derivative(&self, t: V::Scalar) -> V {
+        let u = of::<V>(1.0) - t;
+        let two = of::<V>(2.0);
+        ((self.p1 - self.p0) * (u * u) + (self.p2 - self.p1) * (two * u * t) + (self.p3 - self.p2) * (t * t)) * of::<V>(3.0)
+    }
+    /// the second derivative at `t`.
+    pub fn second_derivative(&self, t: V::Scalar) -> V {
+        let u = of::<V>(1.0) - t;
+        let two = of::<V>(2.0);
+        ((self.p2 - self.p1 * two + self.p0) * u + (self.p3 - self.p2 * two + self.p1) * t) * of::<V>(6.0)
+    }
+    /// splits the curve at `t` into the curve before and after it.
+    pub fn split(self, t: V::Scalar) -> (Self, Self) {
+        let lerp = |a: V, b: V| a + (b - a) * t;
+        let (a, b, c) = (lerp(self.p0, self.p1), lerp(self.p1, self.p2), lerp(self.p2, self.p3));
+        let (d, e) = (lerp(a, b), lerp(b, c));
+        let f = lerp(d, e);
+        (Self::new(self.p0, a, d, f), Self::new(f, e, c, self.p3))
+    }
+    /// the smallest box containing the curve, from its ends and the points where the derivative of
+    /// each axis is 0.0.
+    pub fn bounds(&self) -> (V, V) {
+        let (mut min, mut max) = (self.p0.min(self.p3), self.p0.max(self.p3));
+        for axis in 0..V::DIMENSIONS {
+            let [a, b, c] = [self.p1 - self.p0, self.p2 - self.p1, self.p3 - self.p2].map(|v| v[axis].to_f64());
+            for t in quadratic_roots(a - 2.0 * b + c, 2.0 * (b - a), a) {
+                if t > 0.0 && t < 1.0 {
+                    let p = self.point(of::<V>(t));
+                    (min, max) = (min.min(p), max.max(p));
+                }
+            }
+        }
+        (min, max)
+    }
+}
+/// the real roots of `a * t^2 + b * t + c`.
+fn quadratic_roots(a: f64, b: f64, c: f64) -> Vec<f64> {
+    if a.abs() <= 1e-12 * (b.abs() + c.abs()) {
+        return if b == 0.0 { vec![] } else { vec![-c / b] }
+    }
+    let discriminant = b * b - 4.0 * a * c;
+    if discriminant < 0.0 { return vec![] }
+    let root = discriminant.sqrt();
+    vec![(-b - root) / (2.0 * a), (-b + root) / (2.0 * a)]
+}
+impl<V: CurveVector> Hermite<V> {
+    pub const fn new(p0: V, v0: V, p1: V, v1: V) -> Self { Self { p0, v0, p1, v1 } }
+    /// splits the curve at `t` into the curve before and after it.
+    pub fn split(self, t: V::Scalar) -> (Self, Self) {
+        let bezier = CubicBezier::from(self);
+        let (p, v) = (bezier.point(t), bezier.derivative(t));
+        let u = of::<V>(1.0) - t;
+        (Self::new(self.p0, self.v0 * t, p, v * t), Self::new(p, v * u, self.p1, self.v1 * u))
+    }
+}
+
+/// the same curve as a cubic bézier.
+impl<V: CurveVector> From<QuadraticBezier<V>> for CubicBezier<V> {
+    fn from(value: QuadraticBezier<V>) -> Self {
+        let third = of::<V>(2.0 / 3.0);
+        let QuadraticBezier { p0, p1, p2 } = value;
+        Self::new(p0, p0 + (p1 - p0) * third, p2 + (p1 - p2) * third, p2)
+    }
+}
+impl<V: CurveVector> From<Hermite<V>> for CubicBezier<V> {
+    fn from(value: Hermite<V>) -> Self {
+        let third = of::<V>(1.0 / 3.0);
+        Self::new(value.p0, value.p0 + value.v0 * third, value.p1 - value.v1 * third, value.p1)
+    }
+}
+impl<V: CurveVector> From<CubicBezier<V>> for Hermite<V> {
+    fn from(value: CubicBezier<V>) -> Self {
+        let three = of::<V>(3.0);
+        Self::new(value.p0, (value.p1 - value.p0) * three, value.p3, (value.p3 - value.p2) * three)
+    }
+}
+
+impl<V: CurveVector> Curve<V> for QuadraticBezier<V> {
+    fn segment_count(&self) -> usize { 1 }
+    fn segment(&self, _: usize) -> CubicBezier<V> { (*self).into() }
+}
+impl<V: CurveVector> Curve<V> for CubicBezier<V> {
+    fn segment_count(&self) -> usize { 1 }
+    fn segment(&self, _: usize) -> CubicBezier<V> { *self }
+}
+impl<V: CurveVector> Curve<V> for Hermite<V> {
+    fn segment_count(&self) -> usize { 1 }
+    fn segment(&self, _: usize) -> CubicBezier<V> { (*self).into() }
+}
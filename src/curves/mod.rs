@@ -0,0 +1,446 @@
+//! curves through `Vec2`, `Vec3`, `DVec2` or `DVec3` points, for camera paths, animation and lines.
+//! - `QuadraticBezier`, `CubicBezier` and `Hermite` are single curves. a `Hermite` goes from `p0`
+//!   to `p1`, starting with the velocity `v0` and ending with `v1`.
+//! - `CatmullRom` goes through all of its points. centripetal is usually best since it never has
+//!   loops or cusps, uniform is the classic version and chordal is the roundest at tight corners.
+//! - `BSpline` is a uniform cubic b-spline, which is smoother than catmull rom but only goes near
+//!   its points.
+//!
+//! they all implement `Curve`, which treats them as a list of cubic bézier segments. `t` goes from
+//! 0.0 at the start to 1.0 at the end with each segment taking an equal part, so the speed changes
+//! along the curve. `ArcLength` finds `t` from a distance along the curve to move at a constant
+//! speed.
+//! ```
+//! use glium_types::{prelude::*, curves::*};
+//! let path = CatmullRom::centripetal(vec![
+//!     vec3(0.0, 0.0, 0.0), vec3(1.0, 2.0, 0.0), vec3(3.0, 2.0, 1.0), vec3(4.0, 0.0, 0.0)
+//! ]);
+//! let camera = path.point(0.25);
+//! let forward = path.derivative(0.25).normalise();
+//! let (min, max) = path.bounds();
+//! assert!(min.x == 0.0 && max.x == 4.0 && max.y > 2.0);
+//! // the same point moving at a constant speed
+//! let arc_length = ArcLength::new(&path, 32);
+//! let halfway = path.point(arc_length.t_at(arc_length.length() / 2.0));
+//! let (t, closest) = path.closest_point(vec3(3.0, 3.0, 0.0));
+//! // draw with `PrimitiveType::LineStrip`
+//! let line: Vec<Vertex> = path.tessellate(0.01);
+//! ```
+use std::{fmt::Debug, ops::{Add, Div, Index, Mul, Sub}};
+use crate::{vectors::*, vert_types::Vertex};
+mod bezier;
+mod spline;
+pub use bezier::{QuadraticBezier, CubicBezier, Hermite};
+pub use spline::{CatmullRom, CatmullRomKind, BSpline};
+
+mod private {
+    pub trait Sealed {}
+}
+/// the scalars of curves and their `t`: `f32` and `f64`.
+pub trait CurveScalar: Copy + PartialOrd + Debug + private::Sealed
+    + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Div<Output = Self> {
+    #[doc(hidden)]
+    fn of(x: f64) -> Self;
+    #[doc(hidden)]
+    fn to_f64(self) -> f64;
+}
+impl private::Sealed for f32 {}
+impl CurveScalar for f32 {
+    fn of(x: f64) -> Self { x as f32 }
+    fn to_f64(self) -> f64 { self as f64 }
+}
+impl private::Sealed for f64 {}
+impl CurveScalar for f64 {
+    fn of(x: f64) -> Self { x }
+    fn to_f64(self) -> f64 { self }
+}
+/// the vectors curves can be made of: `Vec2`, `Vec3`, `DVec2` and `DVec3`.
+pub trait CurveVector: Copy + PartialEq + Debug + private::Sealed
+    + Add<Output = Self> + Sub<Output = Self> + Mul<Self::Scalar, Output = Self>
+    + Index<usize, Output = Self::Scalar> {
+    type Scalar: CurveScalar;
+    #[doc(hidden)]
+    const DIMENSIONS: usize;
+    #[doc(hidden)]
+    fn dot(self, other: Self) -> Self::Scalar;
+    #[doc(hidden)]
+    fn min(self, other: Self) -> Self;
+    #[doc(hidden)]
+    fn max(self, other: Self) -> Self;
+    #[doc(hidden)]
+    fn to_vec3(self) -> Vec3;
+}
+macro_rules! impl_curve_vector {
+    ($($vec:ident, $scalar:ident, $n:literal, |$v:ident| $vec3:expr;)*) => {
+        $(
+            impl private::Sealed for $vec {}
+            impl CurveVector for $vec {
+                type Scalar = $scalar;
+                const DIMENSIONS: usize = $n;
+                fn dot(self, other: Self) -> $scalar { $vec::dot(self, other) }
+                fn min(self, other: Self) -> Self { $vec::min(self, other) }
+                fn max(self, other: Self) -> Self { $vec::max(self, other) }
+                fn to_vec3(self) -> Vec3 {
+                    let $v = self;
+                    $vec3
+                }
+            }
+        )*
+    };
+}
+impl_curve_vector!(
+    Vec2, f32, 2, |v| v.extend(0.0);
+    Vec3, f32, 3, |v| v;
+    DVec2, f64, 2, |v| vec3(v.x as f32, v.y as f32, 0.0);
+    DVec3, f64, 3, |v| vec3(v.x as f32, v.y as f32, v.z as f32);
+);
+fn of<V: CurveVector>(x: f64) -> V::Scalar { V::Scalar::of(x) }
+
+/// a curve made of cubic bézier segments, which each take an equal part of `t` from 0.0 to 1.0.
+/// `t` is clamped to 0.0 to 1.0, and curves with no segments panic.
+pub trait Curve<V: CurveVector> {
+    /// the number of segments.
+    fn segment_count(&self) -> usize;
+    /// segment `i` as a cubic bézier.
+    fn segment(&self, i: usize) -> CubicBezier<V>;
+
+    /// the segment `t` is in and how far through that segment it is.
+    fn locate(&self, t: V::Scalar) -> (usize, V::Scalar) {
+        let n = self.segment_count();
+        assert!(n > 0, "the curve has no segments");
+        let x = t.to_f64().clamp(0.0, 1.0) * n as f64;
+        let i = (x as usize).min(n - 1);
+        (i, of::<V>(x - i as f64))
+    }
+    /// the point at `t`.
+    fn point(&self, t: V::Scalar) -> V {
+        let (i, t) = self.locate(t);
+        self.segment(i).point(t)
+    }
+    /// the derivative at `t`, which is the velocity of a point moving along the curve.
+    fn derivative(&self, t: V::Scalar) -> V {
+        let (i, t) = self.locate(t);
+        self.segment(i).derivative(t) * of::<V>(self.segment_count() as f64)
+    }
+    /// the second derivative at `t`, which is the acceleration of a point moving along the curve.
+    fn second_derivative(&self, t: V::Scalar) -> V {
+        let (i, t) = self.locate(t);
+        self.segment(i).second_derivative(t) * of::<V>(self.segment_count().pow(2) as f64)
+    }
+    /// all of the segments.
+    fn to_beziers(&self) -> Vec<CubicBezier<V>> {
+        (0..self.segment_count()).map(|i| self.segment(i)).collect()
+    }
+    /// splits the curve at `t` into the segments before and after it, which are both curves. they
+    /// have the same shape as the curve, but `t` is shared equally between their segments again.
+    fn split_beziers(&self, t: V::Scalar) -> (Vec<CubicBezier<V>>, Vec<CubicBezier<V>>) {
+        let (i, t) = self.locate(t);
+        let (a, b) = self.segment(i).split(t);
+        let mut before: Vec<_> = (0..i).map(|j| self.segment(j)).collect();
+        before.push(a);
+        let after = std::iter::once(b).chain((i + 1..self.segment_count()).map(|j| self.segment(j))).collect();
+        (before, after)
+    }
+    /// the smallest box containing the whole curve, as its minimum and maximum corners.
+    fn bounds(&self) -> (V, V) {
+        (0..self.segment_count()).map(|i| self.segment(i).bounds())
+            .reduce(|(a, b), (c, d)| (a.min(c), b.max(d)))
+            .expect("the curve has no segments")
+    }
+    /// the length of the curve.
+    fn length(&self) -> V::Scalar {
+        of::<V>((0..self.segment_count()).map(|i| segment_length(&self.segment(i), 0.0, 1.0)).sum())
+    }
+    /// the `t` of the closest point on the curve to `p`, and the point.
+    fn closest_point(&self, p: V) -> (V::Scalar, V) {
+        let n = self.segment_count();
+        let (mut closest, mut t, mut point) = (f64::INFINITY, 0.0, p);
+        for i in 0..n {
+            let segment = self.segment(i);
+            let (u, q) = closest_on_segment(&segment, p);
+            let distance = (q - p).dot(q - p).to_f64();
+            if distance < closest {
+                (closest, t, point) = (distance, (i as f64 + u) / n as f64, q);
+            }
+        }
+        (of::<V>(t), point)
+    }
+    /// the curve as a line strip that is never further than `tolerance` from the curve. there are
+    /// more vertices where the curve bends more. draw it with `PrimitiveType::LineStrip`.
+    fn tessellate(&self, tolerance: V::Scalar) -> Vec<Vertex> {
+        let mut vertices = vec![Vertex::from(self.segment(0).p0.to_vec3())];
+        for i in 0..self.segment_count() {
+            flatten(self.segment(i), tolerance.to_f64(), 0, &mut vertices);
+        }
+        vertices
+    }
+}
+impl<V: CurveVector> Curve<V> for Vec<CubicBezier<V>> {
+    fn segment_count(&self) -> usize { self.len() }
+    fn segment(&self, i: usize) -> CubicBezier<V> { self[i] }
+}
+
+/// the length of a segment from `a` to `b`, using 5 point gauss legendre quadrature on 8 parts.
+fn segment_length<V: CurveVector>(segment: &CubicBezier<V>, a: f64, b: f64) -> f64 {
+    const NODES: [(f64, f64); 5] = [
+        (0.0, 0.5688888888888889), (-0.5384693101056831, 0.4786286704993665),
+        (0.5384693101056831, 0.4786286704993665), (-0.906179845938664, 0.2369268850561891),
+        (0.906179845938664, 0.2369268850561891)
+    ];
+    let step = (b - a) / 8.0;
+    (0..8).map(|i| {
+        let middle = a + step * (i as f64 + 0.5);
+        NODES.iter().map(|&(x, w)| {
+            let d = segment.derivative(of::<V>(middle + x * step / 2.0));
+            w * d.dot(d).to_f64().sqrt()
+        }).sum::<f64>() * step / 2.0
+    }).sum()
+}
+/// the closest point on a segment, starting from the closest of a few samples then using newton's
+/// method on the derivative of the squared distance.
+fn closest_on_segment<V: CurveVector>(segment: &CubicBezier<V>, p: V) -> (f64, V) {
+    const SAMPLES: usize = 16;
+    let distance = |t: f64| {
+        let d = segment.point(of::<V>(t)) - p;
+        d.dot(d).to_f64()
+    };
+    let mut t = (0..=SAMPLES).map(|i| i as f64 / SAMPLES as f64)
+        .min_by(|&a, &b| distance(a).total_cmp(&distance(b))).unwrap_or(0.0);
+    for _ in 0..8 {
+        let (d, d1, d2) = (segment.point(of::<V>(t)) - p, segment.derivative(of::<V>(t)), segment.second_derivative(of::<V>(t)));
+        let slope = d.dot(d1).to_f64();
+        let curvature = (d1.dot(d1) + d.dot(d2)).to_f64();
+        if curvature <= 0.0 { break }
+        let next = (t - slope / curvature).clamp(0.0, 1.0);
+        if distance(next) >= distance(t) { break }
+        t = next;
+    }
+    (t, segment.point(of::<V>(t)))
+}
+/// adds the end of `segment` to `vertices`, after splitting it until each part is flat enough. the
+/// curve is at most a quarter of the larger of `u` and `v` from the line between its ends.
+fn flatten<V: CurveVector>(segment: CubicBezier<V>, tolerance: f64, depth: u32, vertices: &mut Vec<Vertex>) {
+    let CubicBezier { p0, p1, p2, p3 } = segment;
+    let u = p1 * of::<V>(3.0) - p0 * of::<V>(2.0) - p3;
+    let v = p2 * of::<V>(3.0) - p0 - p3 * of::<V>(2.0);
+    let flatness = u.dot(u).to_f64().max(v.dot(v).to_f64());
+    if flatness <= 16.0 * tolerance * tolerance || depth >= 16 {
+        vertices.push(p3.to_vec3().into());
+    } else {
+        let (a, b) = segment.split(of::<V>(0.5));
+        flatten(a, tolerance, depth + 1, vertices);
+        flatten(b, tolerance, depth + 1, vertices);
+    }
+}
+
+/// the length of a curve at evenly spaced `t`, to find the `t` a distance along the curve.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ArcLength<S> {
+    lengths: Vec<S>,
+    /// the slopes of `t` against the distance at the ends of each part, scaled to the part.
+    slopes: Vec<[S; 2]>
+}
+impl<S: CurveScalar> ArcLength<S> {
+    /// measures `curve` at `samples` evenly spaced `t` in each segment. `t_at` uses the speed of the
+    /// curve at each sample too, so a few samples are usually enough.
+    pub fn new<V: CurveVector<Scalar = S>>(curve: &impl Curve<V>, samples: usize) -> Self {
+        let samples = samples.max(1);
+        let (mut lengths, mut slopes) = (vec![S::of(0.0)], vec![]);
+        let mut total = 0.0;
+        for i in 0..curve.segment_count() {
+            let segment = curve.segment(i);
+            for j in 0..samples {
+                let (a, b) = (j as f64 / samples as f64, (j + 1) as f64 / samples as f64);
+                let length = segment_length(&segment, a, b);
+                // slopes over 3.0 could make `t` go backwards, which happens near cusps
+                let slope = |t: f64| {
+                    let d = segment.derivative(of::<V>(t));
+                    S::of((length * samples as f64 / d.dot(d).to_f64().sqrt()).min(3.0))
+                };
+                total += length;
+                lengths.push(S::of(total));
+                slopes.push([slope(a), slope(b)]);
+            }
+        }
+        Self { lengths, slopes }
+    }
+    /// the length of the curve.
+    pub fn length(&self) -> S { self.lengths[self.lengths.len() - 1] }
+    /// the `t` that is `distance` along the curve, clamped to the ends.
+    pub fn t_at(&self, distance: S) -> S {
+        let (distance, last) = (distance.to_f64(), self.lengths.len() - 1);
+        let i = self.lengths.partition_point(|l| l.to_f64() < distance);
+        if i == 0 { return S::of(0.0) }
+        if i > last { return S::of(1.0) }
+        let (a, b) = (self.lengths[i - 1].to_f64(), self.lengths[i].to_f64());
+        let x = if b > a { (distance - a) / (b - a) } else { 0.0 };
+        // cubic hermite interpolation between the samples
+        let [m0, m1] = self.slopes[i - 1].map(S::to_f64);
+        let part = (x * x * x - 2.0 * x * x + x) * m0 + (3.0 * x * x - 2.0 * x * x * x) + (x * x * x - x * x) * m1;
+        S::of((i as f64 - 1.0 + part.clamp(0.0, 1.0)) / last as f64)
+    }
+    /// the `t` that is `fraction` of the way along the curve, from 0.0 to 1.0.
+    pub fn t_at_fraction(&self, fraction: S) -> S { self.t_at(fraction * self.length()) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    fn close<V: CurveVector>(a: V, b: V, tolerance: f64) -> bool {
+        (a - b).dot(a - b).to_f64().sqrt() <= tolerance
+    }
+    /// checks the derivatives against the change in the curve over a small step.
+    fn check_derivatives<V: CurveVector>(curve: &impl Curve<V>, tolerance: f64) {
+        let step = 1e-3;
+        // away from the joins between segments, where the second derivative can jump
+        for i in 0..100 {
+            let t = (i as f64 + 0.37) / 100.0;
+            let [a, b] = [t - step, t + step].map(|t| (curve.point(of::<V>(t)), curve.derivative(of::<V>(t))));
+            let inv = of::<V>(1.0 / (2.0 * step));
+            assert!(close((b.0 - a.0) * inv, curve.derivative(of::<V>(t)), tolerance), "{t}");
+            assert!(close((b.1 - a.1) * inv, curve.second_derivative(of::<V>(t)), tolerance * 10.0), "{t}");
+        }
+    }
+    fn path() -> Vec<Vec3> {
+        vec![vec3(0.0, 0.0, 0.0), vec3(1.0, 2.0, 0.0), vec3(1.5, 2.0, 0.5), vec3(4.0, -1.0, 1.0), vec3(5.0, 0.0, 0.0)]
+    }
+    #[test]
+    fn beziers() {
+        let cubic = CubicBezier::new(vec2(0.0, 0.0), vec2(0.0, 1.0), vec2(1.0, 1.0), vec2(1.0, 0.0));
+        assert!(cubic.point(0.5) == vec2(0.5, 0.75) && cubic.derivative(0.0) == vec2(0.0, 3.0));
+        check_derivatives(&cubic, 1e-3);
+        let quadratic = QuadraticBezier::new(dvec3(0.0, 0.0, 0.0), dvec3(1.0, 2.0, 0.0), dvec3(2.0, 0.0, 4.0));
+        let at = |t: f64| quadratic.p0 * ((1.0 - t) * (1.0 - t)) + quadratic.p1 * (2.0 * t * (1.0 - t)) + quadratic.p2 * (t * t);
+        for t in [0.0, 0.3, 0.5, 1.0] {
+            assert!(close(quadratic.point(t), at(t), 1e-12));
+        }
+        check_derivatives(&quadratic, 1e-6);
+        let hermite = Hermite::new(vec3(0.0, 0.0, 0.0), vec3(3.0, 0.0, 0.0), vec3(1.0, 1.0, 0.0), vec3(0.0, 2.0, 0.0));
+        assert!(hermite.derivative(0.0) == hermite.v0 && hermite.derivative(1.0) == hermite.v1);
+        assert!(hermite.point(1.0) == hermite.p1 && Hermite::from(CubicBezier::from(hermite)) == hermite);
+        check_derivatives(&hermite, 1e-3);
+    }
+    #[test]
+    fn subdivision() {
+        let cubic = CubicBezier::new(vec3(0.0, 0.0, 0.0), vec3(1.0, 3.0, 0.0), vec3(2.0, -1.0, 1.0), vec3(4.0, 0.0, 0.0));
+        let (a, b) = cubic.split(0.3);
+        let quadratic = QuadraticBezier::new(vec2(0.0, 0.0), vec2(2.0, 2.0), vec2(3.0, 0.0));
+        let (c, d) = quadratic.split(0.6);
+        let hermite = Hermite::from(cubic);
+        let (e, f) = hermite.split(0.3);
+        for i in 0..=10 {
+            let t = i as f32 / 10.0;
+            assert!(close(a.point(t), cubic.point(t * 0.3), 1e-5) && close(b.point(t), cubic.point(0.3 + t * 0.7), 1e-5));
+            assert!(close(c.point(t), quadratic.point(t * 0.6), 1e-5) && close(d.point(t), quadratic.point(0.6 + t * 0.4), 1e-5));
+            assert!(close(e.point(t), cubic.point(t * 0.3), 1e-5) && close(f.point(t), cubic.point(0.3 + t * 0.7), 1e-5));
+        }
+        // splitting any curve keeps its shape
+        let spline = CatmullRom::chordal(path());
+        let (before, after) = spline.split_beziers(0.6);
+        assert!(before.len() == 3 && after.len() == 2);
+        assert!(before.point(1.0) == after.point(0.0) && close(after.point(0.0), spline.point(0.6), 1e-5));
+        assert!(close(before.point(0.0), spline.point(0.0), 0.0) && close(after.point(1.0), spline.point(1.0), 1e-6));
+        // subdividing a b-spline keeps the same curve with twice as many segments
+        let b_spline = BSpline::new(path());
+        let finer = b_spline.subdivide();
+        assert!(finer.segment_count() == 2 * b_spline.segment_count());
+        for i in 0..=20 {
+            let t = i as f32 / 20.0;
+            assert!(close(finer.point(t), b_spline.point(t), 1e-5), "{t}");
+        }
+    }
+    #[test]
+    fn splines() {
+        let points = path();
+        for kind in [CatmullRomKind::Uniform, CatmullRomKind::Centripetal, CatmullRomKind::Chordal] {
+            let spline = CatmullRom::new(points.clone(), kind);
+            assert!(spline.segment_count() == 4);
+            for (i, &p) in points.iter().enumerate() {
+                assert!(close(spline.point(i as f32 / 4.0), p, 1e-6), "{kind:?} {i}");
+            }
+            // the segments join smoothly, but only uniform ones keep the same speed
+            for i in 1..4 {
+                let [a, b] = [spline.segment(i - 1).derivative(1.0), spline.segment(i).derivative(0.0)];
+                assert!(close(a.normalise(), b.normalise(), 1e-5), "{kind:?} {a:?} {b:?}");
+                assert!(kind != CatmullRomKind::Uniform || close(a, b, 1e-5));
+            }
+            check_derivatives(&spline, 1e-2);
+        }
+        let uniform = CatmullRom::uniform(points.clone());
+        assert!(close(uniform.segment(1).derivative(0.0), (points[2] - points[0]) * 0.5, 1e-6));
+        // b-splines are smooth but don't go through their points, unless they are clamped
+        let b_spline = BSpline::new(points.clone());
+        assert!(b_spline.segment_count() == 2 && b_spline.point(0.0) == (points[0] + points[1] * 4.0 + points[2]) / 6.0);
+        check_derivatives(&b_spline, 1e-2);
+        let clamped = BSpline::clamped(points.clone());
+        assert!(clamped.point(0.0) == points[0] && close(clamped.point(1.0), points[4], 1e-6));
+        for i in 1..clamped.segment_count() {
+            let [a, b] = [clamped.segment(i - 1), clamped.segment(i)];
+            assert!(close(a.second_derivative(1.0), b.second_derivative(0.0), 1e-4));
+        }
+    }
+    #[test]
+    fn bounds() {
+        let cubic = CubicBezier::new(vec2(0.0, 0.0), vec2(0.0, 1.0), vec2(1.0, 1.0), vec2(1.0, 0.0));
+        assert!(cubic.bounds() == (vec2(0.0, 0.0), vec2(1.0, 0.75)));
+        let spline = CatmullRom::centripetal(path());
+        let (min, max) = spline.bounds();
+        let samples: Vec<Vec3> = (0..=4000).map(|i| spline.point(i as f32 / 4000.0)).collect();
+        let (lowest, highest) = samples.iter().fold((samples[0], samples[0]), |(a, b), &p| (a.min(p), b.max(p)));
+        // every point is inside and the box touches the curve
+        assert!(lowest.more_or_eq(min - Vec3::splat(1e-5)).all() && highest.less_or_eq(max + Vec3::splat(1e-5)).all());
+        assert!(close(lowest, min, 1e-4) && close(highest, max, 1e-4), "{min:?} {max:?} {lowest:?} {highest:?}");
+    }
+    #[test]
+    fn arc_length() {
+        // a straight line with uneven control points still has the right length
+        let line = CubicBezier::new(dvec2(0.0, 0.0), dvec2(0.1, 0.0), dvec2(0.2, 0.0), dvec2(3.0, 0.0));
+        assert!((line.length() - 3.0).abs() < 1e-9);
+        let arc_length = ArcLength::new(&line, 64);
+        for i in 0..=10 {
+            let distance = i as f64 * 0.3;
+            assert!((line.point(arc_length.t_at(distance)).x - distance).abs() < 1e-3, "{distance}");
+        }
+        assert!(arc_length.t_at(-1.0) == 0.0 && arc_length.t_at(4.0) == 1.0 && arc_length.t_at_fraction(1.0) == 1.0);
+        let spline = CatmullRom::centripetal(path());
+        let polyline: f32 = (0..10000).map(|i| spline.point(i as f32 / 1e4).distance(spline.point((i + 1) as f32 / 1e4))).sum();
+        assert!((spline.length() - polyline).abs() < 1e-3, "{} {polyline}", spline.length());
+        // equal steps along the curve are equal distances apart
+        let arc_length = ArcLength::new(&spline, 16);
+        let points: Vec<Vec3> = (0..=200).map(|i| spline.point(arc_length.t_at_fraction(i as f32 / 200.0))).collect();
+        for pair in points.windows(2) {
+            assert!((pair[0].distance(pair[1]) - arc_length.length() / 200.0).abs() < 1e-4, "{}", pair[0].distance(pair[1]) - arc_length.length() / 200.0);
+        }
+    }
+    #[test]
+    fn closest_point() {
+        let spline = CatmullRom::centripetal(path());
+        assert!(close(spline.closest_point(spline.point(0.7)).1, spline.point(0.7), 1e-5));
+        for p in [vec3(2.0, 3.0, 0.0), vec3(-1.0, -1.0, 0.0), vec3(3.0, 0.0, 4.0), vec3(4.5, -2.0, 0.5)] {
+            let (t, closest) = spline.closest_point(p);
+            assert!(close(closest, spline.point(t), 1e-5));
+            let nearest = (0..=10000).map(|i| spline.point(i as f32 / 1e4).distance(p)).fold(f32::INFINITY, f32::min);
+            assert!(closest.distance(p) <= nearest + 1e-5, "{p:?}");
+        }
+    }
+    #[test]
+    fn tessellate() {
+        let spline = CatmullRom::centripetal(path().into_iter().map(|p| dvec3(p.x as f64, p.y as f64, p.z as f64)).collect());
+        for tolerance in [0.1, 0.01, 0.001] {
+            let line = spline.tessellate(tolerance);
+            let points: Vec<Vec3> = line.iter().map(|&v| v.into()).collect();
+            assert!(points[0] == vec3(0.0, 0.0, 0.0) && points[points.len() - 1] == vec3(5.0, 0.0, 0.0));
+            // every point on the curve is close to the line
+            let distance_to_line = |p: Vec3| points.windows(2).map(|pair| {
+                let (a, b) = (pair[0], pair[1]);
+                let t = ((p - a).dot(b - a) / (b - a).length_squared()).clamp(0.0, 1.0);
+                p.distance(a + (b - a) * t)
+            }).fold(f32::INFINITY, f32::min);
+            for i in 0..=1000 {
+                let p = spline.point(i as f64 / 1000.0).to_vec3();
+                assert!(distance_to_line(p) <= tolerance as f32 + 1e-5, "{tolerance} {p:?}");
+            }
+            if tolerance == 0.1 { assert!(points.len() < 40, "{}", points.len()) }
+        }
+    }
+}
@@ -0,0 +1,112 @@
+//! splines made of a list of points, with a cubic segment between each pair of points.
+use super::*;
+
+/// how a `CatmullRom` spaces its points, which changes how it goes around corners. the time
+/// between two points is their distance to the power of `alpha`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CatmullRomKind {
+    /// the same time between every point. can overshoot or loop where points are close.
+    Uniform,
+    /// never has loops or cusps in a segment.
+    #[default]
+    Centripetal,
+    /// the time between points is their distance, which gives the roundest corners.
+    Chordal
+}
+impl CatmullRomKind {
+    /// 0.0 for uniform, 0.5 for centripetal and 1.0 for chordal.
+    pub const fn alpha(self) -> f64 {
+        match self {
+            Self::Uniform => 0.0,
+            Self::Centripetal => 0.5,
+            Self::Chordal => 1.0
+        }
+    }
+}
+/// a spline that goes through all of its points, with a segment between each pair. the ends
+/// continue as if there was another point the same distance past them. unless it is uniform the
+/// speed can change suddenly at the points, so use `ArcLength` for smooth movement.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CatmullRom<V> {
+    pub points: Vec<V>,
+    pub kind: CatmullRomKind
+}
+impl<V: CurveVector> CatmullRom<V> {
+    /// panics if there are less than 2 points.
+    pub fn new(points: Vec<V>, kind: CatmullRomKind) -> Self {
+        assert!(points.len() >= 2, "catmull rom splines need at least 2 points but there are {}", points.len());
+        Self { points, kind }
+    }
+    /// a uniform catmull rom spline. panics if there are less than 2 points.
+    pub fn uniform(points: Vec<V>) -> Self { Self::new(points, CatmullRomKind::Uniform) }
+    /// a centripetal catmull rom spline. panics if there are less than 2 points.
+    pub fn centripetal(points: Vec<V>) -> Self { Self::new(points, CatmullRomKind::Centripetal) }
+    /// a chordal catmull rom spline. panics if there are less than 2 points.
+    pub fn chordal(points: Vec<V>) -> Self { Self::new(points, CatmullRomKind::Chordal) }
+}
+impl<V: CurveVector> Curve<V> for CatmullRom<V> {
+    fn segment_count(&self) -> usize { self.points.len().saturating_sub(1) }
+    fn segment(&self, i: usize) -> CubicBezier<V> {
+        let (points, last) = (&self.points, self.points.len() - 1);
+        let (p1, p2) = (points[i], points[i + 1]);
+        let two = of::<V>(2.0);
+        let p0 = if i == 0 { p1 * two - p2 } else { points[i - 1] };
+        let p3 = if i + 1 == last { p2 * two - p1 } else { points[i + 2] };
+        let alpha = self.kind.alpha();
+        let time = |a: V, b: V| {
+            let time = (b - a).dot(b - a).to_f64().powf(alpha / 2.0);
+            if time > 0.0 { time } else { 1.0 }
+        };
+        let (t0, t1, t2) = (time(p0, p1), time(p1, p2), time(p2, p3));
+        // the tangents from barry and goldman's pyramid, scaled to a segment from 0.0 to 1.0
+        let v1 = (p1 - p0) * of::<V>(1.0 / t0) - (p2 - p0) * of::<V>(1.0 / (t0 + t1)) + (p2 - p1) * of::<V>(1.0 / t1);
+        let v2 = (p2 - p1) * of::<V>(1.0 / t1) - (p3 - p1) * of::<V>(1.0 / (t1 + t2)) + (p3 - p2) * of::<V>(1.0 / t2);
+        Hermite::new(p1, v1 * of::<V>(t1), p2, v2 * of::<V>(t1)).into()
+    }
+}
+
+/// a uniform cubic b-spline, with a segment for each 4 points in a row. it has a smooth second
+/// derivative everywhere, but only goes near its points.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BSpline<V> {
+    pub points: Vec<V>
+}
+impl<V: CurveVector> BSpline<V> {
+    /// panics if there are less than 4 points.
+    pub fn new(points: Vec<V>) -> Self {
+        assert!(points.len() >= 4, "b-splines need at least 4 points but there are {}", points.len());
+        Self { points }
+    }
+    /// a spline that starts at the first point and ends at the last, by repeating them 3 times.
+    /// panics if there are less than 2 points.
+    pub fn clamped(points: Vec<V>) -> Self {
+        assert!(points.len() >= 2, "clamped b-splines need at least 2 points but there are {}", points.len());
+        let (first, last) = (points[0], points[points.len() - 1]);
+        Self::new([first, first].into_iter().chain(points).chain([last, last]).collect())
+    }
+    /// the same curve with twice as many segments, with points that are closer to the curve.
+    pub fn subdivide(&self) -> Self {
+        let points = &self.points;
+        let (half, eighth, six) = (of::<V>(0.5), of::<V>(0.125), of::<V>(6.0));
+        let mut subdivided = vec![(points[0] + points[1]) * half];
+        for i in 1..points.len() - 1 {
+            subdivided.push((points[i - 1] + points[i] * six + points[i + 1]) * eighth);
+            subdivided.push((points[i] + points[i + 1]) * half);
+        }
+        Self { points: subdivided }
+    }
+}
+impl<V: CurveVector> Curve<V> for BSpline<V> {
+    fn segment_count(&self) -> usize { self.points.len().saturating_sub(3) }
+    fn segment(&self, i: usize) -> CubicBezier<V> {
+        let [p0, p1, p2, p3]: [V; 4] = self.points[i..i + 4].try_into().unwrap();
+        let (sixth, third, four, two) = (of::<V>(1.0 / 6.0), of::<V>(1.0 / 3.0), of::<V>(4.0), of::<V>(2.0));
+        CubicBezier::new(
+            (p0 + p1 * four + p2) * sixth, (p1 * two + p2) * third,
+            (p1 + p2 * two) * third, (p1 + p2 * four + p3) * sixth
+        )
+    }
+}
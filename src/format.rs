@@ -0,0 +1,270 @@
+//! `Display` and `FromStr` for vectors, matrices and quaternions. vectors and quaternions are
+//! written like glsl constructors, e.g `vec3(1, 2, 3)`, and matrices as a grid of rows, the same
+//! order as `from_values`. precision is passed on to each component, so `{:.2}` rounds them.
+//! ```
+//! use glium_types::prelude::*;
+//! assert_eq!(format!("{:.1}", vec3(1.0, 2.25, 3.0)), "vec3(1.0, 2.2, 3.0)");
+//! assert_eq!(Mat2::from_values(1.0, 20.0, 3.0, 4.0).to_string(), "[1, 20]\n[3,  4]");
+//! assert_eq!("[1, 2, 3]".parse(), Ok(vec3(1.0, 2.0, 3.0)));
+//! assert_eq!("mat2([1, 2], [3, 4])".parse(), Ok(Mat2::from_values(1.0, 2.0, 3.0, 4.0)));
+//! assert!("vec3(1, 2)".parse::<Vec3>().is_err());
+//! ```
+use std::{fmt, str::FromStr};
+use crate::{vectors::*, matrices::*, quaternions::*};
+
+/// why a vector, matrix or quaternion couldn't be parsed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseError {
+    /// there was nothing to parse.
+    Empty,
+    /// a different name was used before the brackets, e.g `dvec3(..)` when parsing a `Vec3`.
+    WrongName { expected: &'static str, found: String },
+    /// a bracket wasn't closed, or there were brackets inside a list of components.
+    Brackets,
+    /// a matrix row wasn't in square brackets.
+    ExpectedRow,
+    /// there were the wrong number of components in a vector, quaternion or matrix row.
+    ComponentCount { expected: usize, found: usize },
+    /// there were the wrong number of rows in a matrix.
+    RowCount { expected: usize, found: usize },
+    /// a component wasn't a valid number, or `true` or `false` for boolean vectors.
+    InvalidComponent { index: usize, text: String },
+    /// a row of a matrix couldn't be parsed.
+    Row { index: usize, error: Box<ParseError> },
+}
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "nothing to parse"),
+            Self::WrongName { expected, found } => write!(f, "expected `{expected}`, found `{found}`"),
+            Self::Brackets => write!(f, "unmatched brackets"),
+            Self::ExpectedRow => write!(f, "expected each row of the matrix in square brackets, like `[1, 2]`"),
+            Self::ComponentCount { expected, found } => write!(f, "expected {expected} components, found {found}"),
+            Self::RowCount { expected, found } => write!(f, "expected {expected} rows, found {found}"),
+            Self::InvalidComponent { index, text } => write!(f, "component {index} is not valid: `{text}`"),
+            Self::Row { index, error } => write!(f, "row {index}: {error}"),
+        }
+    }
+}
+impl std::error::Error for ParseError {}
+
+/// a component of a vector, matrix or quaternion.
+trait Component: Copy + fmt::Display + FromStr {
+    fn format(self, precision: Option<usize>) -> String {
+        match precision {
+            Some(precision) => format!("{self:.precision$}"),
+            None => self.to_string()
+        }
+    }
+}
+impl Component for f32 {}
+impl Component for f64 {}
+impl Component for i32 {}
+impl Component for u32 {}
+impl Component for i64 {}
+impl Component for u64 {}
+impl Component for bool {
+    // precision would cut off the end of `true` and `false`
+    fn format(self, _precision: Option<usize>) -> String { self.to_string() }
+}
+
+fn write_list<T: Component>(f: &mut fmt::Formatter<'_>, name: &str, components: &[T]) -> fmt::Result {
+    let components: Vec<_> = components.iter().map(|c| c.format(f.precision())).collect();
+    write!(f, "{name}({})", components.join(", "))
+}
+/// writes the rows of a matrix with each column lined up.
+fn write_rows<T: Component, const N: usize>(f: &mut fmt::Formatter<'_>, rows: [[T; N]; N]) -> fmt::Result {
+    let rows = rows.map(|row| row.map(|c| c.format(f.precision())));
+    let widths: [usize; N] = std::array::from_fn(|c| rows.iter().map(|row| row[c].len()).max().unwrap_or(0));
+    for (i, row) in rows.iter().enumerate() {
+        if i > 0 { writeln!(f)? }
+        let row: Vec<_> = row.iter().zip(widths).map(|(c, width)| format!("{c:>width$}")).collect();
+        write!(f, "[{}]", row.join(", "))?;
+    }
+    Ok(())
+}
+
+/// splits `text` into a name followed by brackets, e.g `vec3(..)`, returning what is inside them.
+fn strip_name<'a>(text: &'a str, name: &'static str) -> Result<Option<&'a str>, ParseError> {
+    let end = text.find(|c: char| !(c.is_alphanumeric() || c == '_')).unwrap_or(text.len());
+    let (found, rest) = text.split_at(end);
+    // `inf` and `true` also start with letters, so it is only a name if it is followed by brackets
+    let Some(rest) = rest.trim_start().strip_prefix('(').filter(|_| !found.is_empty()) else { return Ok(None) };
+    if found != name {
+        return Err(ParseError::WrongName { expected: name, found: found.to_owned() })
+    }
+    rest.strip_suffix(')').map(Some).ok_or(ParseError::Brackets)
+}
+fn strip_brackets(text: &str, open: char, close: char) -> Result<Option<&str>, ParseError> {
+    match text.strip_prefix(open) {
+        Some(inner) => inner.strip_suffix(close).map(Some).ok_or(ParseError::Brackets),
+        None => Ok(None)
+    }
+}
+/// parses a list of components like `vec3(1, 2, 3)`, `(1, 2, 3)`, `[1, 2, 3]` or `1, 2, 3`.
+fn parse_list<T: Component, const N: usize>(text: &str, name: &'static str) -> Result<[T; N], ParseError> {
+    let text = text.trim();
+    if text.is_empty() { return Err(ParseError::Empty) }
+    let inner = match strip_name(text, name)? {
+        Some(inner) => inner,
+        None => strip_brackets(text, '(', ')')?.or(strip_brackets(text, '[', ']')?).unwrap_or(text)
+    };
+    if inner.contains(['(', ')', '[', ']']) { return Err(ParseError::Brackets) }
+    let components: Vec<_> = match inner.trim() {
+        "" => Vec::new(),
+        inner => inner.split(',').map(str::trim).collect()
+    };
+    if components.len() != N {
+        return Err(ParseError::ComponentCount { expected: N, found: components.len() })
+    }
+    let mut parsed = Vec::with_capacity(N);
+    for (index, text) in components.into_iter().enumerate() {
+        let component = text.parse().map_err(|_| ParseError::InvalidComponent { index, text: text.to_owned() })?;
+        parsed.push(component);
+    }
+    Ok(parsed.try_into().unwrap_or_else(|_| unreachable!()))
+}
+/// parses the rows of a matrix like `[1, 2]\n[3, 4]`, optionally inside `mat2(..)` or `[..]`.
+fn parse_rows<T: Component, const N: usize>(text: &str, name: &'static str) -> Result<[[T; N]; N], ParseError> {
+    let text = text.trim();
+    if text.is_empty() { return Err(ParseError::Empty) }
+    let mut rest = match strip_name(text, name)? {
+        Some(inner) => inner.trim(),
+        // the outer brackets of `[[1, 2], [3, 4]]`
+        None => match text.strip_prefix('[') {
+            Some(inner) if inner.trim_start().starts_with('[') => {
+                inner.strip_suffix(']').ok_or(ParseError::Brackets)?.trim()
+            },
+            _ => text
+        }
+    };
+    let mut rows = Vec::new();
+    while !rest.is_empty() {
+        if !rest.starts_with('[') { return Err(ParseError::ExpectedRow) }
+        let end = rest.find(']').ok_or(ParseError::Brackets)?;
+        let index = rows.len();
+        let row = parse_list(&rest[..=end], name).map_err(|error| ParseError::Row { index, error: Box::new(error) })?;
+        rows.push(row);
+        rest = rest[end + 1..].trim_start();
+        rest = rest.strip_prefix(',').unwrap_or(rest).trim_start();
+    }
+    if rows.len() != N {
+        return Err(ParseError::RowCount { expected: N, found: rows.len() })
+    }
+    Ok(rows.try_into().unwrap_or_else(|_| unreachable!()))
+}
+
+macro_rules! impl_format_list {
+    ($($ty:ident, $scalar:ty, $name:literal { $($c:ident)* };)*) => {
+        $(
+            impl fmt::Display for $ty {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    write_list::<$scalar>(f, $name, &[$(self.$c),*])
+                }
+            }
+            impl FromStr for $ty {
+                type Err = ParseError;
+                fn from_str(s: &str) -> Result<Self, ParseError> {
+                    let [$($c),*] = parse_list(s, $name)?;
+                    Ok($ty { $($c),* })
+                }
+            }
+        )*
+    };
+}
+impl_format_list!(
+    Vec2, f32, "vec2" { x y }; Vec3, f32, "vec3" { x y z }; Vec4, f32, "vec4" { x y z w };
+    DVec2, f64, "dvec2" { x y }; DVec3, f64, "dvec3" { x y z }; DVec4, f64, "dvec4" { x y z w };
+    IVec2, i32, "ivec2" { x y }; IVec3, i32, "ivec3" { x y z }; IVec4, i32, "ivec4" { x y z w };
+    UVec2, u32, "uvec2" { x y }; UVec3, u32, "uvec3" { x y z }; UVec4, u32, "uvec4" { x y z w };
+    DIVec2, i64, "i64vec2" { x y }; DIVec3, i64, "i64vec3" { x y z }; DIVec4, i64, "i64vec4" { x y z w };
+    DUVec2, u64, "u64vec2" { x y }; DUVec3, u64, "u64vec3" { x y z }; DUVec4, u64, "u64vec4" { x y z w };
+    BVec2, bool, "bvec2" { x y }; BVec3, bool, "bvec3" { x y z }; BVec4, bool, "bvec4" { x y z w };
+    Quat, f32, "quat" { r i j k }; DQuat, f64, "dquat" { r i j k };
+);
+
+macro_rules! impl_format_matrix {
+    ($($ty:ident::$from:ident, $scalar:ty, $n:literal, $name:literal;)*) => {
+        $(
+            impl fmt::Display for $ty {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    write_rows::<$scalar, $n>(f, std::array::from_fn(|i| self.row(i)))
+                }
+            }
+            impl FromStr for $ty {
+                type Err = ParseError;
+                fn from_str(s: &str) -> Result<Self, ParseError> {
+                    let rows = parse_rows::<$scalar, $n>(s, $name)?;
+                    Ok($ty::$from(std::array::from_fn(|c| rows.map(|row| row[c]))))
+                }
+            }
+        )*
+    };
+}
+impl_format_matrix!(
+    Mat2::from_column_major_array, f32, 2, "mat2"; Mat3::from_colum_major_array, f32, 3, "mat3";
+    Mat4::from_column_major_array, f32, 4, "mat4"; DMat2::from_column_major_array, f64, 2, "dmat2";
+    DMat3::from_column_major_array, f64, 3, "dmat3"; DMat4::from_column_major_array, f64, 4, "dmat4";
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use super::ParseError;
+    #[test]
+    fn display() {
+        assert_eq!(vec3(1.0, -2.5, 3.0).to_string(), "vec3(1, -2.5, 3)");
+        assert_eq!(format!("{:.3}", dvec2(1.0, 1.0 / 3.0)), "dvec2(1.000, 0.333)");
+        assert_eq!(format!("{:.3}", ivec2(1, -2)), "ivec2(1, -2)");
+        assert_eq!(format!("{:.2}", bvec2(true, false)), "bvec2(true, false)");
+        assert_eq!(duvec3(1, 2, u64::MAX).to_string(), format!("u64vec3(1, 2, {})", u64::MAX));
+        assert_eq!(Quat::IDENTITY.to_string(), "quat(1, 0, 0, 0)");
+        let m = Mat4::from_pos(vec3(1.0, -20.0, 3.5));
+        assert_eq!(m.to_string(), "\
+[1, 0, 0,   1]
+[0, 1, 0, -20]
+[0, 0, 1, 3.5]
+[0, 0, 0,   1]");
+        assert_eq!(format!("{:.1}", DMat2::from_values(1.0, 2.0, -3.0, 4.0)), "[ 1.0, 2.0]\n[-3.0, 4.0]");
+    }
+    #[test]
+    fn parse() {
+        assert_eq!("vec3(1, 2, 3)".parse(), Ok(vec3(1.0, 2.0, 3.0)));
+        assert_eq!(" [1, 2.5, -3e2] ".parse(), Ok(vec3(1.0, 2.5, -300.0)));
+        assert_eq!("(inf, 2)".parse(), Ok(vec2(f32::INFINITY, 2.0)));
+        assert_eq!("true, false, true".parse(), Ok(bvec3(true, false, true)));
+        assert_eq!("i64vec2(-1, 2)".parse(), Ok(divec2(-1, 2)));
+        assert_eq!("quat(1, 0, 0, 0)".parse(), Ok(Quat::IDENTITY));
+        let m = Mat3::from_values(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+        assert_eq!("[1, 2, 3]\n[4, 5, 6]\n[7, 8, 9]".parse(), Ok(m));
+        assert_eq!("[[1, 2, 3], [4, 5, 6], [7, 8, 9]]".parse(), Ok(m));
+        assert_eq!("mat3(\n    [1, 2, 3],\n    [4, 5, 6],\n    [7, 8, 9]\n)".parse(), Ok(m));
+        let m = Mat4::from_values(
+            1.0, 2.0, 3.0, 4.0,
+            5.0, 6.0, 7.0, 8.0,
+            9.0, 10.0, 11.0, 12.0,
+            13.0, 14.0, 15.0, 16.0
+        );
+        assert_eq!(m.to_string().parse(), Ok(m));
+        assert_eq!(format!("{:.3}", m).parse(), Ok(m));
+        let v = dvec4(0.1, 1.0 / 3.0, -2.0, 1e-10);
+        assert_eq!(v.to_string().parse(), Ok(v));
+    }
+    #[test]
+    fn parse_errors() {
+        assert_eq!("".parse::<Vec3>(), Err(ParseError::Empty));
+        let error = ParseError::WrongName { expected: "vec3", found: "dvec3".to_owned() };
+        assert_eq!("dvec3(1, 2, 3)".parse::<Vec3>(), Err(error));
+        assert_eq!("vec3(1, 2, 3".parse::<Vec3>(), Err(ParseError::Brackets));
+        assert_eq!("[1, (2), 3]".parse::<Vec3>(), Err(ParseError::Brackets));
+        assert_eq!("vec3(1, 2)".parse::<Vec3>(), Err(ParseError::ComponentCount { expected: 3, found: 2 }));
+        assert_eq!("vec2()".parse::<Vec2>(), Err(ParseError::ComponentCount { expected: 2, found: 0 }));
+        let error = "[1, x, 3]".parse::<IVec3>().unwrap_err();
+        assert_eq!(error, ParseError::InvalidComponent { index: 1, text: "x".to_owned() });
+        assert_eq!(error.to_string(), "component 1 is not valid: `x`");
+        assert_eq!("1, 2, 3, 4".parse::<Mat2>(), Err(ParseError::ExpectedRow));
+        assert_eq!("[1, 2]".parse::<Mat2>(), Err(ParseError::RowCount { expected: 2, found: 1 }));
+        let error = "[1, 2]\n[3]".parse::<Mat2>().unwrap_err();
+        assert_eq!(error.to_string(), "row 1: expected 2 components, found 1");
+    }
+}
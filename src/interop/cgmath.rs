@@ -0,0 +1,97 @@
+//! `From` conversions between the crate's types and the `cgmath` types. cgmath matrices are also
+//! stored as columns.
+use ::cgmath::{Vector2, Vector3, Vector4, Matrix2, Matrix3, Matrix4, Quaternion};
+use crate::{vectors::*, matrices::*, quaternions::*};
+
+macro_rules! impl_cgmath_vector {
+    ($($ty:ident, $scalar:ty => $cg:ident { $($c:ident)* };)*) => {
+        $(
+            impl From<$ty> for $cg<$scalar> {
+                fn from(value: $ty) -> Self { $cg { $($c: value.$c),* } }
+            }
+            impl From<$cg<$scalar>> for $ty {
+                fn from(value: $cg<$scalar>) -> Self { $ty { $($c: value.$c),* } }
+            }
+        )*
+    };
+}
+impl_cgmath_vector!(
+    Vec2, f32 => Vector2 { x y }; Vec3, f32 => Vector3 { x y z }; Vec4, f32 => Vector4 { x y z w };
+    DVec2, f64 => Vector2 { x y }; DVec3, f64 => Vector3 { x y z }; DVec4, f64 => Vector4 { x y z w };
+    IVec2, i32 => Vector2 { x y }; IVec3, i32 => Vector3 { x y z }; IVec4, i32 => Vector4 { x y z w };
+    UVec2, u32 => Vector2 { x y }; UVec3, u32 => Vector3 { x y z }; UVec4, u32 => Vector4 { x y z w };
+    DIVec2, i64 => Vector2 { x y }; DIVec3, i64 => Vector3 { x y z }; DIVec4, i64 => Vector4 { x y z w };
+    DUVec2, u64 => Vector2 { x y }; DUVec3, u64 => Vector3 { x y z }; DUVec4, u64 => Vector4 { x y z w };
+    BVec2, bool => Vector2 { x y }; BVec3, bool => Vector3 { x y z }; BVec4, bool => Vector4 { x y z w };
+);
+
+macro_rules! impl_cgmath_quat {
+    ($($ty:ident, $scalar:ty;)*) => {
+        $(
+            impl From<$ty> for Quaternion<$scalar> {
+                fn from(value: $ty) -> Self {
+                    Quaternion { v: Vector3 { x: value.i, y: value.j, z: value.k }, s: value.r }
+                }
+            }
+            impl From<Quaternion<$scalar>> for $ty {
+                fn from(value: Quaternion<$scalar>) -> Self {
+                    $ty { r: value.s, i: value.v.x, j: value.v.y, k: value.v.z }
+                }
+            }
+        )*
+    };
+}
+impl_cgmath_quat!(Quat, f32; DQuat, f64;);
+
+macro_rules! impl_cgmath_matrix {
+    ($($ty:ident::$from:ident, $scalar:ty => $cg:ident;)*) => {
+        $(
+            impl From<$ty> for $cg<$scalar> {
+                fn from(value: $ty) -> Self { value.into_column_major_array().into() }
+            }
+            impl From<$cg<$scalar>> for $ty {
+                fn from(value: $cg<$scalar>) -> Self { $ty::$from(value.into()) }
+            }
+        )*
+    };
+}
+impl_cgmath_matrix!(
+    Mat2::from_column_major_array, f32 => Matrix2; Mat3::from_colum_major_array, f32 => Matrix3;
+    Mat4::from_column_major_array, f32 => Matrix4; DMat2::from_column_major_array, f64 => Matrix2;
+    DMat3::from_column_major_array, f64 => Matrix3; DMat4::from_column_major_array, f64 => Matrix4;
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::{prelude::*, testing::*};
+    crate::interop::round_trip_test!(
+        cgmath::Vector4<f32>, cgmath::Vector3<i32>, cgmath::Vector2<u64>, cgmath::Vector3<bool>,
+        cgmath::Matrix3<f32>, cgmath::Matrix4<f32>, cgmath::Matrix4<f64>,
+        cgmath::Quaternion<f32> => |q| [q.v.x, q.v.y, q.v.z, q.s]
+    );
+    #[test]
+    fn transforms_agree() {
+        let mut random = Random::new();
+        for _ in 0..CASES {
+            let (a, b, v) = (random.mat4(), random.mat4(), random.vec4());
+            let product = cgmath::Matrix4::from(a) * cgmath::Vector4::from(v);
+            assert_approx_eq!(Vec4::from(product), a * v, epsilon = 1e-3);
+            let product = cgmath::Matrix4::from(a) * cgmath::Matrix4::from(b);
+            assert_approx_eq!(Mat4::from(product), a * b, epsilon = 1e-2);
+            let (m, v) = (random.mat3(), random.vec3());
+            let product = cgmath::Matrix3::from(m) * cgmath::Vector3::from(v);
+            assert_approx_eq!(Vec3::from(product), m * v, epsilon = 1e-3);
+            let (m, v) = (random.dmat4(), random.dvec4());
+            let product = cgmath::Matrix4::from(m) * cgmath::Vector4::from(v);
+            assert_approx_eq!(DVec4::from(product), m * v, epsilon = 1e-9);
+            let (p, q, v) = (random.quat(), random.quat(), random.vec3());
+            assert_approx_eq!(Quat::from(cgmath::Quaternion::from(p) * cgmath::Quaternion::from(q)), p * q);
+            let rotated = cgmath::Quaternion::from(q) * cgmath::Vector3::from(v);
+            assert_approx_eq!(Vec3::from(rotated).extend(0.0), Mat4::from(q) * v.extend(0.0), epsilon = 1e-4);
+            assert_approx_eq!(Mat4::from(cgmath::Matrix4::from(cgmath::Quaternion::from(q))), Mat4::from(q));
+            let (q, v) = (random.dquat(), random.dvec3());
+            let rotated = cgmath::Quaternion::from(q) * cgmath::Vector3::from(v);
+            assert_approx_eq!(DVec3::from(rotated), DMat3::from(q) * v, epsilon = 1e-9);
+        }
+    }
+}
@@ -0,0 +1,87 @@
+//! `From` conversions between the crate's types and the `glam` types. glam quaternions store the
+//! real part last (`xyzw`), so `Quat { r, i, j, k }` becomes `glam::Quat::from_xyzw(i, j, k, r)`.
+use crate::{vectors::*, matrices::*, quaternions::*};
+
+macro_rules! impl_glam_vector {
+    ($($ty:ident => $glam:ident { $($c:ident)* };)*) => {
+        $(
+            impl From<$ty> for ::glam::$glam {
+                fn from(value: $ty) -> Self { ::glam::$glam::new($(value.$c),*) }
+            }
+            impl From<::glam::$glam> for $ty {
+                fn from(value: ::glam::$glam) -> Self { $ty { $($c: value.$c),* } }
+            }
+        )*
+    };
+}
+impl_glam_vector!(
+    Vec2 => Vec2 { x y }; Vec3 => Vec3 { x y z }; Vec4 => Vec4 { x y z w };
+    DVec2 => DVec2 { x y }; DVec3 => DVec3 { x y z }; DVec4 => DVec4 { x y z w };
+    IVec2 => IVec2 { x y }; IVec3 => IVec3 { x y z }; IVec4 => IVec4 { x y z w };
+    UVec2 => UVec2 { x y }; UVec3 => UVec3 { x y z }; UVec4 => UVec4 { x y z w };
+    DIVec2 => I64Vec2 { x y }; DIVec3 => I64Vec3 { x y z }; DIVec4 => I64Vec4 { x y z w };
+    DUVec2 => U64Vec2 { x y }; DUVec3 => U64Vec3 { x y z }; DUVec4 => U64Vec4 { x y z w };
+    BVec2 => BVec2 { x y }; BVec3 => BVec3 { x y z }; BVec4 => BVec4 { x y z w };
+);
+
+macro_rules! impl_glam_quat {
+    ($($ty:ident => $glam:ident;)*) => {
+        $(
+            impl From<$ty> for ::glam::$glam {
+                fn from(value: $ty) -> Self { ::glam::$glam::from_xyzw(value.i, value.j, value.k, value.r) }
+            }
+            impl From<::glam::$glam> for $ty {
+                fn from(value: ::glam::$glam) -> Self { $ty { r: value.w, i: value.x, j: value.y, k: value.z } }
+            }
+        )*
+    };
+}
+impl_glam_quat!(Quat => Quat; DQuat => DQuat;);
+
+macro_rules! impl_glam_matrix {
+    ($($ty:ident::$from:ident => $glam:ident;)*) => {
+        $(
+            impl From<$ty> for ::glam::$glam {
+                fn from(value: $ty) -> Self { ::glam::$glam::from_cols_array_2d(&value.into_column_major_array()) }
+            }
+            impl From<::glam::$glam> for $ty {
+                fn from(value: ::glam::$glam) -> Self { $ty::$from(value.to_cols_array_2d()) }
+            }
+        )*
+    };
+}
+impl_glam_matrix!(
+    Mat2::from_column_major_array => Mat2; Mat3::from_colum_major_array => Mat3;
+    Mat4::from_column_major_array => Mat4; DMat2::from_column_major_array => DMat2;
+    DMat3::from_column_major_array => DMat3; DMat4::from_column_major_array => DMat4;
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::{prelude::*, testing::*};
+    crate::interop::round_trip_test!(
+        glam::Vec4, glam::IVec3, glam::U64Vec2, glam::BVec3, glam::Mat3, glam::Mat4, glam::DMat4,
+        glam::Quat => |q| q.to_array()
+    );
+    #[test]
+    fn transforms_agree() {
+        let mut random = Random::new();
+        for _ in 0..CASES {
+            let (a, b, v) = (random.mat4(), random.mat4(), random.vec4());
+            assert_approx_eq!(Vec4::from(glam::Mat4::from(a) * glam::Vec4::from(v)), a * v, epsilon = 1e-3);
+            assert_approx_eq!(Mat4::from(glam::Mat4::from(a) * glam::Mat4::from(b)), a * b, epsilon = 1e-2);
+            let (m, v) = (random.mat3(), random.vec3());
+            assert_approx_eq!(Vec3::from(glam::Mat3::from(m) * glam::Vec3::from(v)), m * v, epsilon = 1e-3);
+            let (m, v) = (random.dmat4(), random.dvec4());
+            assert_approx_eq!(DVec4::from(glam::DMat4::from(m) * glam::DVec4::from(v)), m * v, epsilon = 1e-9);
+            let (p, q, v) = (random.quat(), random.quat(), random.vec3());
+            assert_approx_eq!(Quat::from(glam::Quat::from(p) * glam::Quat::from(q)), p * q);
+            let rotated = glam::Quat::from(q) * glam::Vec3::from(v);
+            assert_approx_eq!(Vec3::from(rotated).extend(0.0), Mat4::from(q) * v.extend(0.0), epsilon = 1e-4);
+            assert_approx_eq!(Mat4::from(glam::Mat4::from_quat(q.into())), Mat4::from(q));
+            let (q, v) = (random.dquat(), random.dvec3());
+            let rotated = glam::DQuat::from(q) * glam::DVec3::from(v);
+            assert_approx_eq!(DVec3::from(rotated), DMat3::from(q) * v, epsilon = 1e-9);
+        }
+    }
+}
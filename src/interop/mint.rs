@@ -0,0 +1,117 @@
+//! `From` conversions and `IntoMint` for the `mint` types. matrices convert to column matrices, so
+//! no transposing is needed.
+use ::mint::{
+    IntoMint, Vector2, Vector3, Vector4, Quaternion, ColumnMatrix2, ColumnMatrix3, ColumnMatrix4
+};
+use crate::{vectors::*, matrices::*, quaternions::*};
+
+macro_rules! impl_mint_vector {
+    ($($ty:ident, $scalar:ty, $mint:ident { $($c:ident)* };)*) => {
+        $(
+            impl From<$ty> for $mint<$scalar> {
+                fn from(value: $ty) -> Self { $mint { $($c: value.$c),* } }
+            }
+            impl From<$mint<$scalar>> for $ty {
+                fn from(value: $mint<$scalar>) -> Self { $ty { $($c: value.$c),* } }
+            }
+            impl IntoMint for $ty {
+                type MintType = $mint<$scalar>;
+            }
+        )*
+    };
+}
+impl_mint_vector!(
+    Vec2, f32, Vector2 { x y }; Vec3, f32, Vector3 { x y z }; Vec4, f32, Vector4 { x y z w };
+    DVec2, f64, Vector2 { x y }; DVec3, f64, Vector3 { x y z }; DVec4, f64, Vector4 { x y z w };
+    IVec2, i32, Vector2 { x y }; IVec3, i32, Vector3 { x y z }; IVec4, i32, Vector4 { x y z w };
+    UVec2, u32, Vector2 { x y }; UVec3, u32, Vector3 { x y z }; UVec4, u32, Vector4 { x y z w };
+);
+
+macro_rules! impl_mint_quat {
+    ($($ty:ident, $scalar:ty;)*) => {
+        $(
+            impl From<$ty> for Quaternion<$scalar> {
+                fn from(value: $ty) -> Self {
+                    Quaternion { v: Vector3 { x: value.i, y: value.j, z: value.k }, s: value.r }
+                }
+            }
+            impl From<Quaternion<$scalar>> for $ty {
+                fn from(value: Quaternion<$scalar>) -> Self {
+                    $ty { r: value.s, i: value.v.x, j: value.v.y, k: value.v.z }
+                }
+            }
+            impl IntoMint for $ty {
+                type MintType = Quaternion<$scalar>;
+            }
+        )*
+    };
+}
+impl_mint_quat!(Quat, f32; DQuat, f64;);
+
+macro_rules! impl_mint_matrix {
+    ($($ty:ident::$from:ident, $scalar:ty, $mint:ident;)*) => {
+        $(
+            impl From<$ty> for $mint<$scalar> {
+                fn from(value: $ty) -> Self { value.into_column_major_array().into() }
+            }
+            impl From<$mint<$scalar>> for $ty {
+                fn from(value: $mint<$scalar>) -> Self { $ty::$from(value.into()) }
+            }
+            impl IntoMint for $ty {
+                type MintType = $mint<$scalar>;
+            }
+        )*
+    };
+}
+impl_mint_matrix!(
+    Mat2::from_column_major_array, f32, ColumnMatrix2;
+    Mat3::from_colum_major_array, f32, ColumnMatrix3;
+    Mat4::from_column_major_array, f32, ColumnMatrix4;
+    DMat2::from_column_major_array, f64, ColumnMatrix2;
+    DMat3::from_column_major_array, f64, ColumnMatrix3;
+    DMat4::from_column_major_array, f64, ColumnMatrix4;
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    fn round_trip<T: mint::IntoMint + From<T::MintType> + PartialEq + std::fmt::Debug + Copy>(value: T) {
+        let converted: T::MintType = value.into();
+        assert_eq!(T::from(converted), value);
+    }
+    #[test]
+    fn vectors() {
+        let v: mint::Vector3<f32> = vec3(1.0, 2.0, 3.0).into();
+        assert_eq!(v, mint::Vector3 { x: 1.0, y: 2.0, z: 3.0 });
+        round_trip(dvec4(1.0, 2.0, 3.0, 4.0));
+        round_trip(ivec2(-1, 2));
+        round_trip(uvec3(1, 2, 3));
+        let q: mint::Quaternion<f32> = Quat { r: 1.0, i: 2.0, j: 3.0, k: 4.0 }.into();
+        assert_eq!(q, mint::Quaternion { v: mint::Vector3 { x: 2.0, y: 3.0, z: 4.0 }, s: 1.0 });
+        round_trip(DQuat { r: 1.0, i: 2.0, j: 3.0, k: 4.0 });
+    }
+    #[test]
+    fn matrices() {
+        let m = Mat4::from_values(
+            1.0, 2.0, 3.0, 4.0,
+            5.0, 6.0, 7.0, 8.0,
+            9.0, 10.0, 11.0, 12.0,
+            13.0, 14.0, 15.0, 16.0
+        );
+        let columns: mint::ColumnMatrix4<f32> = m.into();
+        // `from_values` is written in rows, so the first column is the first value of each row
+        assert_eq!(columns.x, mint::Vector4 { x: 1.0, y: 5.0, z: 9.0, w: 13.0 });
+        assert_eq!(columns.w, mint::Vector4 { x: 4.0, y: 8.0, z: 12.0, w: 16.0 });
+        let rows: mint::RowMatrix4<f32> = columns.into();
+        assert_eq!(rows.x, mint::Vector4 { x: 1.0, y: 2.0, z: 3.0, w: 4.0 });
+        assert_eq!(Mat4::from(columns), m);
+        let m = Mat3::from_values(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+        let columns: mint::ColumnMatrix3<f32> = m.into();
+        assert_eq!(columns.y, mint::Vector3 { x: 2.0, y: 5.0, z: 8.0 });
+        round_trip(m);
+        round_trip(Mat2::from_values(1.0, 2.0, 3.0, 4.0));
+        round_trip(DMat2::from_values(1.0, 2.0, 3.0, 4.0));
+        round_trip(DMat3::from_values(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0));
+        round_trip(DMat4::from_pos(dvec3(1.0, 2.0, 3.0)));
+    }
+}
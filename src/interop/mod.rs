@@ -0,0 +1,49 @@
+//! conversions between the crate's types and the types of other math crates. each crate has its
+//! own cargo feature.
+#[cfg(feature = "mint")]
+mod mint;
+#[cfg(feature = "glam")]
+mod glam;
+#[cfg(feature = "nalgebra")]
+mod nalgebra;
+#[cfg(feature = "cgmath")]
+mod cgmath;
+
+/// a test that converts random values to the types of another crate and back, and checks that
+/// quaternions keep their components in the same places. `$xyzw` gives the other crate's
+/// quaternion components in x, y, z, w order.
+#[cfg(all(test, any(feature = "glam", feature = "nalgebra", feature = "cgmath")))]
+macro_rules! round_trip_test {
+    (
+        $vec4:ty, $ivec3:ty, $duvec2:ty, $bvec3:ty, $mat3:ty, $mat4:ty, $dmat4:ty,
+        $quat:ty => |$q:ident| $xyzw:expr
+    ) => {
+        #[test]
+        fn round_trips() {
+            use crate::{prelude::*, testing::*};
+            let mut random = Random::new();
+            for _ in 0..CASES {
+                let v = random.vec4();
+                assert_eq!(Vec4::from(<$vec4>::from(v)), v);
+                let v = ivec3(random.i32(), random.i32(), random.i32());
+                assert_eq!(IVec3::from(<$ivec3>::from(v)), v);
+                let v = duvec2(random.u64(), random.u64());
+                assert_eq!(DUVec2::from(<$duvec2>::from(v)), v);
+                let v = bvec3(random.bool(), random.bool(), random.bool());
+                assert_eq!(BVec3::from(<$bvec3>::from(v)), v);
+                let q = Quat { r: random.f32(), i: random.f32(), j: random.f32(), k: random.f32() };
+                let $q = <$quat>::from(q);
+                assert_eq!($xyzw, [q.i, q.j, q.k, q.r]);
+                assert_eq!(Quat::from($q), q);
+                let m = random.mat3();
+                assert_eq!(Mat3::from(<$mat3>::from(m)), m);
+                let m = random.mat4();
+                assert_eq!(Mat4::from(<$mat4>::from(m)), m);
+                let m = random.dmat4();
+                assert_eq!(DMat4::from(<$dmat4>::from(m)), m);
+            }
+        }
+    };
+}
+#[cfg(all(test, any(feature = "glam", feature = "nalgebra", feature = "cgmath")))]
+use round_trip_test;
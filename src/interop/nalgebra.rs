@@ -0,0 +1,95 @@
+//! `From` conversions between the crate's types and the `nalgebra` types. quaternions convert to
+//! `nalgebra::Quaternion`, and unit quaternions can be converted into them.
+use ::nalgebra::{Vector2, Vector3, Vector4, Matrix2, Matrix3, Matrix4, Quaternion, UnitQuaternion};
+use crate::{vectors::*, matrices::*, quaternions::*};
+
+macro_rules! impl_nalgebra_vector {
+    ($($ty:ident, $scalar:ty => $na:ident { $($c:ident)* };)*) => {
+        $(
+            impl From<$ty> for $na<$scalar> {
+                fn from(value: $ty) -> Self { $na::new($(value.$c),*) }
+            }
+            impl From<$na<$scalar>> for $ty {
+                fn from(value: $na<$scalar>) -> Self { $ty { $($c: value.$c),* } }
+            }
+        )*
+    };
+}
+impl_nalgebra_vector!(
+    Vec2, f32 => Vector2 { x y }; Vec3, f32 => Vector3 { x y z }; Vec4, f32 => Vector4 { x y z w };
+    DVec2, f64 => Vector2 { x y }; DVec3, f64 => Vector3 { x y z }; DVec4, f64 => Vector4 { x y z w };
+    IVec2, i32 => Vector2 { x y }; IVec3, i32 => Vector3 { x y z }; IVec4, i32 => Vector4 { x y z w };
+    UVec2, u32 => Vector2 { x y }; UVec3, u32 => Vector3 { x y z }; UVec4, u32 => Vector4 { x y z w };
+    DIVec2, i64 => Vector2 { x y }; DIVec3, i64 => Vector3 { x y z }; DIVec4, i64 => Vector4 { x y z w };
+    DUVec2, u64 => Vector2 { x y }; DUVec3, u64 => Vector3 { x y z }; DUVec4, u64 => Vector4 { x y z w };
+    BVec2, bool => Vector2 { x y }; BVec3, bool => Vector3 { x y z }; BVec4, bool => Vector4 { x y z w };
+);
+
+macro_rules! impl_nalgebra_quat {
+    ($($ty:ident, $scalar:ty;)*) => {
+        $(
+            impl From<$ty> for Quaternion<$scalar> {
+                fn from(value: $ty) -> Self { Quaternion::new(value.r, value.i, value.j, value.k) }
+            }
+            impl From<Quaternion<$scalar>> for $ty {
+                fn from(value: Quaternion<$scalar>) -> Self { $ty { r: value.w, i: value.i, j: value.j, k: value.k } }
+            }
+            impl From<UnitQuaternion<$scalar>> for $ty {
+                fn from(value: UnitQuaternion<$scalar>) -> Self { value.into_inner().into() }
+            }
+        )*
+    };
+}
+impl_nalgebra_quat!(Quat, f32; DQuat, f64;);
+
+macro_rules! impl_nalgebra_matrix {
+    ($($ty:ident::$from:ident, $scalar:ty => $na:ident;)*) => {
+        $(
+            impl From<$ty> for $na<$scalar> {
+                fn from(value: $ty) -> Self { value.into_column_major_array().into() }
+            }
+            impl From<$na<$scalar>> for $ty {
+                fn from(value: $na<$scalar>) -> Self { $ty::$from(value.into()) }
+            }
+        )*
+    };
+}
+impl_nalgebra_matrix!(
+    Mat2::from_column_major_array, f32 => Matrix2; Mat3::from_colum_major_array, f32 => Matrix3;
+    Mat4::from_column_major_array, f32 => Matrix4; DMat2::from_column_major_array, f64 => Matrix2;
+    DMat3::from_column_major_array, f64 => Matrix3; DMat4::from_column_major_array, f64 => Matrix4;
+);
+
+#[cfg(test)]
+mod tests {
+    use nalgebra as na;
+    use crate::{prelude::*, testing::*};
+    crate::interop::round_trip_test!(
+        na::Vector4<f32>, na::Vector3<i32>, na::Vector2<u64>, na::Vector3<bool>, na::Matrix3<f32>,
+        na::Matrix4<f32>, na::Matrix4<f64>, na::Quaternion<f32> => |q| [q.i, q.j, q.k, q.w]
+    );
+    #[test]
+    fn transforms_agree() {
+        let mut random = Random::new();
+        for _ in 0..CASES {
+            let (a, b, v) = (random.mat4(), random.mat4(), random.vec4());
+            assert_eq!(na::Matrix4::from(a)[(0, 1)], a.row(0)[1]);
+            assert_approx_eq!(Vec4::from(na::Matrix4::from(a) * na::Vector4::from(v)), a * v, epsilon = 1e-3);
+            assert_approx_eq!(Mat4::from(na::Matrix4::from(a) * na::Matrix4::from(b)), a * b, epsilon = 1e-2);
+            let (m, v) = (random.mat3(), random.vec3());
+            assert_approx_eq!(Vec3::from(na::Matrix3::from(m) * na::Vector3::from(v)), m * v, epsilon = 1e-3);
+            let (m, v) = (random.dmat4(), random.dvec4());
+            assert_approx_eq!(DVec4::from(na::Matrix4::from(m) * na::Vector4::from(v)), m * v, epsilon = 1e-9);
+            let (p, q, v) = (random.quat(), random.quat(), random.vec3());
+            assert_approx_eq!(Quat::from(na::Quaternion::from(p) * na::Quaternion::from(q)), p * q);
+            let unit = na::UnitQuaternion::new_unchecked(q.into());
+            assert_approx_eq!(Quat::from(unit), q);
+            let rotated = unit * na::Vector3::from(v);
+            assert_approx_eq!(Vec3::from(rotated).extend(0.0), Mat4::from(q) * v.extend(0.0), epsilon = 1e-4);
+            assert_approx_eq!(Mat4::from(unit.to_homogeneous()), Mat4::from(q));
+            let (q, v) = (random.dquat(), random.dvec3());
+            let rotated = na::UnitQuaternion::new_unchecked(q.into()) * na::Vector3::from(v);
+            assert_approx_eq!(DVec3::from(rotated), DMat3::from(q) * v, epsilon = 1e-9);
+        }
+    }
+}
@@ -0,0 +1,262 @@
+//! memory layouts used by glsl buffer blocks. the traits in here convert the crate's types (and
+//! structs made of them with the derive macros) into padded copies with the exact byte layout glsl
+//! expects, so they can be put straight into a glium buffer.
+//! ```
+//! use glium_types::{prelude::*, layout::Std140};
+//! #[derive(Clone, Copy, Std140)]
+//! struct Light {
+//!     position: Vec3,
+//!     strength: f32,
+//! }
+//! #[derive(Clone, Copy, Std140)]
+//! struct Lights {
+//!     camera: Mat4,
+//!     lights: [Light; 4],
+//!     count: u32,
+//! }
+//! let light = Light { position: Vec3::ZERO, strength: 1.0 };
+//! let padded = Lights { camera: Mat4::IDENTITY, lights: [light; 4], count: 4 }.to_std140();
+//! // the padded copy can be uploaded with `glium::uniforms::UniformBuffer::new(&display, padded)`
+//! assert_eq!(std::mem::size_of_val(&padded), 64 + 4 * 16 + 16);
+//! assert!(Lights::glsl_uniform_block("Lights").contains("Light lights[4];"));
+//! ```
+//! shader storage buffers use `Std430` the same way. a block that is only a runtime sized array
+//! can use a `glium::buffer::Buffer<[T::ArrayElement]>`, otherwise read the contents of the buffer
+//! as bytes and use `from_std430_bytes` or `from_std430_bytes_with_array`.
+//! ```
+//! use glium_types::{prelude::*, layout::*};
+//! #[derive(Clone, Copy, Debug, PartialEq, Std430)]
+//! struct Particles {
+//!     time: f32,
+//!     gravity: Vec3,
+//! }
+//! let particles = Particles { time: 0.5, gravity: -Vec3::Y };
+//! let bytes = to_std430_bytes_with_array(&particles, &[Vec3::X, Vec3::Z]);
+//! assert_eq!(Particles::OFFSETS, [("time", 0), ("gravity", 16)]);
+//! assert_eq!(runtime_array_offset::<Particles, Vec3>(), 32);
+//! assert_eq!(from_std430_bytes_with_array(&bytes), (particles, vec![Vec3::X, Vec3::Z]));
+//! ```
+use glium::{program::BlockLayout, uniforms::{LayoutMismatchError, UniformBlock, UniformType}};
+use crate::{vectors::*, matrices::*, quaternions::*};
+mod std140;
+mod std430;
+pub use std140::*;
+pub use std430::*;
+
+/// a value aligned (and padded) to 16 bytes. used for std140 array elements.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[repr(C, align(16))]
+pub struct Align16<T>(pub T);
+
+/// a value followed by `N` bytes of padding. used for std430 array elements.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[repr(C)]
+pub struct WithPadding<T, const N: usize>(pub T, pub [u8; N]);
+
+/// an array of padded values. unlike plain arrays it can be used in glium uniform blocks with any
+/// length.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[repr(transparent)]
+pub struct PaddedArray<T, const N: usize>(pub [T; N]);
+
+/// a matrix with each of its columns stored in a `Vec4` or `DVec4`, with the unused components set
+/// to 0.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[repr(transparent)]
+pub struct PaddedColumns<C, const N: usize>(pub [C; N]);
+
+macro_rules! impl_padded_columns {
+    ($($mat:ident::$from:ident, $column:ident, $n:literal { $($c:ident)* };)*) => {
+        $(
+            impl From<$mat> for PaddedColumns<$column, $n> {
+                fn from(value: $mat) -> Self {
+                    PaddedColumns(value.into_column_major_array().map(|c| {
+                        let mut column = [0.0; 4];
+                        column[..$n].copy_from_slice(&c);
+                        $column::from(column)
+                    }))
+                }
+            }
+            impl From<PaddedColumns<$column, $n>> for $mat {
+                fn from(value: PaddedColumns<$column, $n>) -> Self {
+                    $mat::$from(value.0.map(|c| [$(c.$c),*]))
+                }
+            }
+        )*
+    };
+}
+impl_padded_columns!(
+    Mat2::from_column_major_array, Vec4, 2 { x y };
+    Mat3::from_colum_major_array, Vec4, 3 { x y z };
+    DMat3::from_column_major_array, DVec4, 3 { x y z };
+);
+
+/// rounds `value` up to the next multiple of `align`.
+pub const fn round_up(value: usize, align: usize) -> usize {
+    value.div_ceil(align) * align
+}
+/// the offset of field `index` in a struct, where `fields` is the alignment and size of each
+/// field.
+pub const fn field_offset(fields: &[(usize, usize)], index: usize) -> usize {
+    let mut offset = 0;
+    let mut i = 0;
+    while i <= index {
+        offset = round_up(offset, fields[i].0);
+        if i < index { offset += fields[i].1 }
+        i += 1;
+    }
+    offset
+}
+/// the padding needed between the end of the previous field and field `index`.
+pub const fn field_padding(fields: &[(usize, usize)], index: usize) -> usize {
+    if index == 0 { return 0 }
+    field_offset(fields, index) - field_offset(fields, index - 1) - fields[index - 1].1
+}
+/// the alignment of a struct. the largest alignment of its fields, rounded up to `min_align`
+/// which is 16 for std140 and 1 for std430.
+pub const fn struct_align(fields: &[(usize, usize)], min_align: usize) -> usize {
+    let mut align = min_align;
+    let mut i = 0;
+    while i < fields.len() {
+        if fields[i].0 > align { align = fields[i].0 }
+        i += 1;
+    }
+    round_up(align, min_align)
+}
+/// where the last field of a struct ends.
+pub const fn struct_end(fields: &[(usize, usize)]) -> usize {
+    let last = fields.len() - 1;
+    field_offset(fields, last) + fields[last].1
+}
+/// the size of a struct, which is rounded up to a multiple of its alignment.
+pub const fn struct_size(fields: &[(usize, usize)], min_align: usize) -> usize {
+    round_up(struct_end(fields), struct_align(fields, min_align))
+}
+/// the padding needed after the last field for the struct to be a multiple of its alignment.
+pub const fn end_padding(fields: &[(usize, usize)], min_align: usize) -> usize {
+    struct_size(fields, min_align) - struct_end(fields)
+}
+/// zeroed padding bytes. used by the derive macros.
+#[doc(hidden)]
+pub const fn padding<const N: usize>() -> [u8; N] { [0; N] }
+
+/// the glsl for a struct with the given members.
+pub fn glsl_struct(name: &str, members: &str) -> String {
+    format!("struct {name} {{\n{members}}};\n")
+}
+/// a line declaring a struct member in glsl.
+pub fn glsl_member(ty: &str, name: &str, array_suffix: &str) -> String {
+    format!("    {ty} {name}{array_suffix};\n")
+}
+
+fn basic_matches(layout: &BlockLayout, base_offset: usize, ty: UniformType) -> Result<(), LayoutMismatchError> {
+    let BlockLayout::BasicType { ty: expected, offset_in_buffer } = *layout else {
+        return Err(LayoutMismatchError::LayoutMismatch {
+            expected: layout.clone(),
+            obtained: BlockLayout::BasicType { ty, offset_in_buffer: base_offset }
+        })
+    };
+    if expected != ty {
+        return Err(LayoutMismatchError::TypeMismatch { expected, obtained: ty })
+    }
+    if offset_in_buffer != base_offset {
+        return Err(LayoutMismatchError::OffsetMismatch { expected: offset_in_buffer, obtained: base_offset })
+    }
+    Ok(())
+}
+macro_rules! impl_uniform_block {
+    ($($ty:ty => $uniform:ident),* $(,)?) => {
+        $(
+            impl UniformBlock for $ty {
+                fn matches(layout: &BlockLayout, base_offset: usize) -> Result<(), LayoutMismatchError> {
+                    basic_matches(layout, base_offset, UniformType::$uniform)
+                }
+                fn build_layout(base_offset: usize) -> BlockLayout {
+                    BlockLayout::BasicType { ty: UniformType::$uniform, offset_in_buffer: base_offset }
+                }
+            }
+        )*
+    };
+}
+impl_uniform_block!(
+    Vec2 => FloatVec2, Vec3 => FloatVec3, Vec4 => FloatVec4,
+    DVec2 => DoubleVec2, DVec3 => DoubleVec3, DVec4 => DoubleVec4,
+    IVec2 => IntVec2, IVec3 => IntVec3, IVec4 => IntVec4,
+    UVec2 => UnsignedIntVec2, UVec3 => UnsignedIntVec3, UVec4 => UnsignedIntVec4,
+    Quat => FloatVec4, DQuat => DoubleVec4,
+    Mat2 => FloatMat2, Mat4 => FloatMat4, DMat2 => DoubleMat2, DMat4 => DoubleMat4,
+    PaddedColumns<Vec4, 2> => FloatMat2, PaddedColumns<Vec4, 3> => FloatMat3,
+    PaddedColumns<DVec4, 3> => DoubleMat3,
+);
+impl<T: UniformBlock> UniformBlock for Align16<T> {
+    fn matches(layout: &BlockLayout, base_offset: usize) -> Result<(), LayoutMismatchError> {
+        T::matches(layout, base_offset)
+    }
+    fn build_layout(base_offset: usize) -> BlockLayout { T::build_layout(base_offset) }
+}
+impl<T: UniformBlock, const N: usize> UniformBlock for WithPadding<T, N> {
+    fn matches(layout: &BlockLayout, base_offset: usize) -> Result<(), LayoutMismatchError> {
+        T::matches(layout, base_offset)
+    }
+    fn build_layout(base_offset: usize) -> BlockLayout { T::build_layout(base_offset) }
+}
+impl<T: UniformBlock, const N: usize> UniformBlock for PaddedArray<T, N> {
+    fn matches(layout: &BlockLayout, base_offset: usize) -> Result<(), LayoutMismatchError> {
+        match layout {
+            BlockLayout::Array { content, length } if *length == N => T::matches(content, base_offset)
+                .map_err(|err| LayoutMismatchError::MemberMismatch {
+                    member: "<array content>".to_owned(), err: Box::new(err)
+                }),
+            _ => Err(LayoutMismatchError::LayoutMismatch {
+                expected: layout.clone(), obtained: Self::build_layout(base_offset)
+            })
+        }
+    }
+    fn build_layout(base_offset: usize) -> BlockLayout {
+        BlockLayout::Array { content: Box::new(T::build_layout(base_offset)), length: N }
+    }
+}
+/// `UniformBlock::matches` for a member.
+type Matches = fn(&BlockLayout, usize) -> Result<(), LayoutMismatchError>;
+/// used by the derive macros to check the members of a struct.
+#[doc(hidden)]
+pub fn struct_matches(
+    layout: &BlockLayout, base_offset: usize, members: &[(&str, usize, Matches)],
+    build_layout: fn(usize) -> BlockLayout
+) -> Result<(), LayoutMismatchError> {
+    let BlockLayout::Struct { members: reflected } = layout else {
+        return Err(LayoutMismatchError::LayoutMismatch {
+            expected: layout.clone(), obtained: build_layout(base_offset)
+        })
+    };
+    if let Some((name, _)) = reflected.iter().find(|(name, _)| members.iter().all(|m| m.0 != name)) {
+        return Err(LayoutMismatchError::MissingField { name: name.clone() })
+    }
+    for (name, offset, matches) in members {
+        let Some((_, layout)) = reflected.iter().find(|(reflected, _)| reflected == name) else {
+            return Err(LayoutMismatchError::MissingField { name: name.to_string() })
+        };
+        matches(layout, base_offset + offset).map_err(|err| LayoutMismatchError::MemberMismatch {
+            member: name.to_string(), err: Box::new(err)
+        })?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn offsets() {
+        // float, vec3, float, vec2
+        let fields = [(4, 4), (16, 12), (4, 4), (8, 8)];
+        assert_eq!([0, 1, 2, 3].map(|i| field_offset(&fields, i)), [0, 16, 28, 32]);
+        assert_eq!([0, 1, 2, 3].map(|i| field_padding(&fields, i)), [0, 12, 0, 0]);
+        assert_eq!(struct_align(&fields, 16), 16);
+        assert_eq!(struct_end(&fields), 40);
+        assert_eq!(end_padding(&fields, 16), 8);
+        assert_eq!(struct_size(&fields, 16), 48);
+        assert_eq!(end_padding(&fields, 1), 8);
+        assert_eq!(end_padding(&[(4, 4), (4, 4), (4, 4)], 1), 0);
+    }
+}
@@ -0,0 +1,191 @@
+use super::*;
+/// derives `Std140` for a struct made of types that implement it. this also creates a padded copy
+/// of the struct named `Std140` followed by the struct name, which implements glium's
+/// `UniformBlock`. generic and tuple structs are not supported.
+pub use glium_types_derive::Std140;
+
+/// a type that can be stored in a glsl `layout(std140)` block. `Padded` is a copy of the type with
+/// the same bytes as the std140 layout, which can be put into a `glium::uniforms::UniformBuffer`.
+/// use `#[derive(Std140)]` to implement it for structs.
+pub trait Std140: Copy {
+    /// the std140 base alignment in bytes.
+    const ALIGN: usize;
+    /// the name and byte offset of each member of a struct. empty for everything else.
+    const OFFSETS: &'static [(&'static str, usize)] = &[];
+    /// a copy of the type with std140 padding. its size is the std140 size of the type.
+    type Padded: Copy + UniformBlock;
+    /// converts into the padded copy.
+    fn to_std140(&self) -> Self::Padded;
+    /// converts back from the padded copy, e.g after reading it out of a buffer.
+    fn from_std140(padded: &Self::Padded) -> Self;
+    /// the name of the type in glsl. arrays use the name of their element.
+    fn glsl_type() -> String;
+    /// what comes after the name of a member with this type, e.g `[4]` for arrays.
+    fn glsl_array_suffix() -> String { String::new() }
+    /// the members of a struct in glsl. empty for everything else.
+    fn glsl_members() -> String { String::new() }
+    /// adds the glsl definition of every struct used by this type (including itself) to
+    /// `definitions`, skipping ones already in it.
+    fn glsl_structs(_definitions: &mut Vec<String>) {}
+    /// the glsl declaration of a `layout(std140) uniform` block with the same members as this
+    /// struct, after the definitions of the structs it uses.
+    fn glsl_uniform_block(block_name: &str) -> String {
+        let mut definitions = Vec::new();
+        Self::glsl_structs(&mut definitions);
+        // the struct itself is always added last
+        definitions.pop();
+        let members = Self::glsl_members();
+        definitions.concat() + &format!("layout(std140) uniform {block_name} {{\n{members}}};\n")
+    }
+}
+macro_rules! impl_std140 {
+    ($($ty:ty, $glsl:literal, $align:expr;)*) => {
+        $(
+            impl Std140 for $ty {
+                const ALIGN: usize = $align;
+                type Padded = $ty;
+                fn to_std140(&self) -> Self::Padded { *self }
+                fn from_std140(padded: &Self::Padded) -> Self { *padded }
+                fn glsl_type() -> String { $glsl.to_owned() }
+            }
+        )*
+    };
+}
+impl_std140!(
+    f32, "float", 4; i32, "int", 4; u32, "uint", 4; f64, "double", 8;
+    Vec2, "vec2", 8; Vec3, "vec3", 16; Vec4, "vec4", 16;
+    IVec2, "ivec2", 8; IVec3, "ivec3", 16; IVec4, "ivec4", 16;
+    UVec2, "uvec2", 8; UVec3, "uvec3", 16; UVec4, "uvec4", 16;
+    DVec2, "dvec2", 16; DVec3, "dvec3", 32; DVec4, "dvec4", 32;
+    Quat, "vec4", 16; DQuat, "dvec4", 32;
+    Mat4, "mat4", 16; DMat2, "dmat2", 16; DMat4, "dmat4", 32;
+);
+macro_rules! impl_std140_padded_matrix {
+    ($($mat:ident, $column:ident, $n:literal, $glsl:literal, $align:expr;)*) => {
+        $(
+            impl Std140 for $mat {
+                const ALIGN: usize = $align;
+                type Padded = PaddedColumns<$column, $n>;
+                fn to_std140(&self) -> Self::Padded { (*self).into() }
+                fn from_std140(padded: &Self::Padded) -> Self { (*padded).into() }
+                fn glsl_type() -> String { $glsl.to_owned() }
+            }
+        )*
+    };
+}
+impl_std140_padded_matrix!(
+    Mat2, Vec4, 2, "mat2", 16;
+    Mat3, Vec4, 3, "mat3", 16;
+    DMat3, DVec4, 3, "dmat3", 32;
+);
+/// arrays round the alignment and stride of their elements up to 16 bytes.
+impl<T: Std140, const N: usize> Std140 for [T; N] {
+    const ALIGN: usize = round_up(T::ALIGN, 16);
+    type Padded = PaddedArray<Align16<T::Padded>, N>;
+    fn to_std140(&self) -> Self::Padded { PaddedArray(self.each_ref().map(|v| Align16(v.to_std140()))) }
+    fn from_std140(padded: &Self::Padded) -> Self { padded.0.each_ref().map(|v| T::from_std140(&v.0)) }
+    fn glsl_type() -> String { T::glsl_type() }
+    fn glsl_array_suffix() -> String { format!("[{N}]{}", T::glsl_array_suffix()) }
+    fn glsl_structs(definitions: &mut Vec<String>) { T::glsl_structs(definitions) }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::mem::{offset_of, size_of};
+    use super::*;
+    #[derive(Clone, Copy, Debug, PartialEq, Std140)]
+    pub struct Light {
+        colour: Vec3,
+        strength: f32,
+        direction: Vec2,
+    }
+    #[derive(Clone, Copy, Debug, PartialEq, Std140)]
+    pub struct Scene {
+        pub a: f32,
+        pub normal_matrix: Mat3,
+        pub b: f32,
+        pub lights: [Light; 2],
+        pub weights: [f32; 3],
+        pub c: Vec2,
+        pub d: DVec3,
+        pub e: u32,
+    }
+    fn scene() -> Scene {
+        let light = Light { colour: Vec3::ONE, strength: 2.0, direction: Vec2::X };
+        Scene {
+            a: 1.0, normal_matrix: Mat3::from_scale(vec3(1.0, 2.0, 3.0)), b: 3.0,
+            lights: [light, Light { strength: 0.5, ..light }], weights: [0.25, 0.5, 0.75],
+            c: vec2(4.0, 5.0), d: dvec3(6.0, 7.0, 8.0), e: 9
+        }
+    }
+    #[test]
+    fn std140_offsets() {
+        // vec3 is aligned to 16, but a float can fill the space after it
+        assert_eq!(offset_of!(Std140Light, colour), 0);
+        assert_eq!(offset_of!(Std140Light, strength), 12);
+        assert_eq!(offset_of!(Std140Light, direction), 16);
+        assert_eq!(size_of::<Std140Light>(), 32);
+        assert_eq!(Light::ALIGN, 16);
+        assert_eq!(Light::OFFSETS, [("colour", 0), ("strength", 12), ("direction", 16)]);
+        assert_eq!(offset_of!(Std140Scene, a), 0);
+        // matrix columns are padded to vec4s
+        assert_eq!(offset_of!(Std140Scene, normal_matrix), 16);
+        assert_eq!(offset_of!(Std140Scene, b), 64);
+        // structs in arrays are aligned to 16
+        assert_eq!(offset_of!(Std140Scene, lights), 80);
+        // each float in an array takes up 16 bytes
+        assert_eq!(offset_of!(Std140Scene, weights), 144);
+        assert_eq!(offset_of!(Std140Scene, c), 192);
+        // dvec3 is aligned to 32
+        assert_eq!(offset_of!(Std140Scene, d), 224);
+        assert_eq!(offset_of!(Std140Scene, e), 248);
+        assert_eq!(Scene::ALIGN, 32);
+        assert_eq!(size_of::<Std140Scene>(), 256);
+        assert_eq!(size_of::<<[Vec3; 3] as Std140>::Padded>(), 48);
+        assert_eq!(size_of::<<DMat3 as Std140>::Padded>(), 96);
+    }
+    #[test]
+    fn std140_bytes() {
+        let padded = scene().to_std140();
+        // only reads bytes that are part of a value, since padding may be uninitialised
+        let start = &padded as *const Std140Scene as *const u8;
+        let float = |offset: usize| unsafe { (start.add(offset) as *const f32).read_unaligned() };
+        assert_eq!([float(0), float(16), float(36), float(56), float(64)], [1.0, 1.0, 2.0, 3.0, 3.0]);
+        assert_eq!([float(80 + 12), float(112 + 12), float(112 + 16)], [2.0, 0.5, 1.0]);
+        assert_eq!([float(144), float(160), float(176), float(196)], [0.25, 0.5, 0.75, 5.0]);
+        assert_eq!(unsafe { (start.add(240) as *const f64).read_unaligned() }, 8.0);
+        assert_eq!(Scene::from_std140(&padded), scene());
+    }
+    #[test]
+    fn std140_glsl() {
+        assert_eq!(Scene::glsl_uniform_block("SceneBlock"), "struct Light {
+    vec3 colour;
+    float strength;
+    vec2 direction;
+};
+layout(std140) uniform SceneBlock {
+    float a;
+    mat3 normal_matrix;
+    float b;
+    Light lights[2];
+    float weights[3];
+    vec2 c;
+    dvec3 d;
+    uint e;
+};
+");
+    }
+    #[test]
+    fn std140_uniform_block() {
+        let layout = Std140Scene::build_layout(0);
+        assert!(Std140Scene::matches(&layout, 0).is_ok());
+        let BlockLayout::Struct { members } = &layout else { panic!() };
+        let BlockLayout::Array { content, length: 2 } = &members[3].1 else { panic!() };
+        let BlockLayout::Struct { members } = content.as_ref() else { panic!() };
+        assert_eq!(members[2].0, "direction");
+        assert!(matches!(members[2].1, BlockLayout::BasicType { offset_in_buffer: 96, .. }));
+        let BlockLayout::Struct { members } = Std140Light::build_layout(0) else { panic!() };
+        let moved = BlockLayout::Struct { members: vec![members[0].clone(), members[1].clone()] };
+        assert!(Std140Light::matches(&moved, 0).is_err());
+    }
+}
@@ -0,0 +1,281 @@
+use std::mem::size_of;
+use super::*;
+/// derives `Std430` for a struct made of types that implement it. this also creates a padded copy
+/// of the struct named `Std430` followed by the struct name, which implements glium's
+/// `UniformBlock`. generic and tuple structs are not supported.
+pub use glium_types_derive::Std430;
+
+/// a type that can be stored in a glsl `layout(std430)` block, used by shader storage buffers.
+/// unlike std140, arrays and structs are not rounded up to 16 bytes. `Padded` is a copy of the
+/// type with the same bytes as the std430 layout, which can be put into a `glium::buffer::Buffer`.
+/// use `#[derive(Std430)]` to implement it for structs.
+///
+/// # Safety
+/// `Padded` and `ArrayElement` must not have any padding bytes that aren't fields, and any bytes
+/// must be a valid value for them, since they are converted to and from bytes.
+pub unsafe trait Std430: Copy {
+    /// the std430 base alignment in bytes.
+    const ALIGN: usize;
+    /// where the last member of a struct ends, before the padding after it. the same as the size
+    /// for everything else.
+    const END: usize = size_of::<Self::Padded>();
+    /// the name and byte offset of each member of a struct. empty for everything else.
+    const OFFSETS: &'static [(&'static str, usize)] = &[];
+    /// a copy of the type with std430 padding. its size is the std430 size of the type.
+    type Padded: Copy + UniformBlock;
+    /// a copy of the type with std430 padding when it is in an array. its size is the array stride.
+    type ArrayElement: Copy + UniformBlock;
+    /// converts into the padded copy.
+    fn to_std430(&self) -> Self::Padded;
+    /// converts back from the padded copy.
+    fn from_std430(padded: &Self::Padded) -> Self;
+    /// converts into the padded copy used in arrays.
+    fn to_std430_element(&self) -> Self::ArrayElement;
+    /// converts back from the padded copy used in arrays.
+    fn from_std430_element(element: &Self::ArrayElement) -> Self;
+    /// the name of the type in glsl. arrays use the name of their element.
+    fn glsl_type() -> String;
+    /// what comes after the name of a member with this type, e.g `[4]` for arrays.
+    fn glsl_array_suffix() -> String { String::new() }
+    /// the members of a struct in glsl. empty for everything else.
+    fn glsl_members() -> String { String::new() }
+    /// adds the glsl definition of every struct used by this type (including itself) to
+    /// `definitions`, skipping ones already in it.
+    fn glsl_structs(_definitions: &mut Vec<String>) {}
+    /// the glsl declaration of a `layout(std430) buffer` block with the same members as this
+    /// struct, after the definitions of the structs it uses.
+    fn glsl_buffer_block(block_name: &str) -> String {
+        glsl_buffer_block::<Self>(block_name, "")
+    }
+    /// the std430 bytes of the value.
+    fn to_std430_bytes(&self) -> Vec<u8> { bytes_of(&self.to_std430()).to_vec() }
+    /// reads the value from std430 bytes, e.g the contents of a `glium::buffer::Buffer<[u8]>`.
+    /// panics if there aren't enough bytes.
+    fn from_std430_bytes(bytes: &[u8]) -> Self {
+        assert!(bytes.len() >= size_of::<Self::Padded>(), "not enough bytes for a std430 value");
+        Self::from_std430(&read(bytes))
+    }
+}
+fn bytes_of<T: Copy>(value: &T) -> &[u8] {
+    // safe as long as `T` is a `Padded` or `ArrayElement` type, which have no uninitialised bytes
+    unsafe { std::slice::from_raw_parts(value as *const T as *const u8, size_of::<T>()) }
+}
+fn read<T: Copy>(bytes: &[u8]) -> T {
+    // safe as long as `T` is a `Padded` or `ArrayElement` type, which are valid for any bytes
+    unsafe { (bytes.as_ptr() as *const T).read_unaligned() }
+}
+fn glsl_buffer_block<H: Std430>(block_name: &str, extra_members: &str) -> String {
+    let mut definitions = Vec::new();
+    H::glsl_structs(&mut definitions);
+    // the struct itself is always added last
+    definitions.pop();
+    let members = H::glsl_members();
+    definitions.concat() + &format!("layout(std430) buffer {block_name} {{\n{members}{extra_members}}};\n")
+}
+
+/// the byte offset of a runtime sized array of `T` that comes after the members of `H` in a std430
+/// block, like `particles` in `buffer Particles { float time; Particle particles[]; }`.
+pub const fn runtime_array_offset<H: Std430, T: Std430>() -> usize {
+    round_up(H::END, T::ALIGN)
+}
+/// the std430 bytes of a block made of the members of `header` followed by a runtime sized array.
+pub fn to_std430_bytes_with_array<H: Std430, T: Std430>(header: &H, array: &[T]) -> Vec<u8> {
+    let offset = runtime_array_offset::<H, T>();
+    let mut bytes = header.to_std430_bytes();
+    // the array can start inside the padding at the end of the header
+    bytes.resize(offset, 0);
+    for element in array {
+        bytes.extend_from_slice(bytes_of(&element.to_std430_element()));
+    }
+    bytes
+}
+/// reads a block made of the members of `H` followed by a runtime sized array of `T`. the length
+/// of the array depends on the number of bytes.
+pub fn from_std430_bytes_with_array<H: Std430, T: Std430>(bytes: &[u8]) -> (H, Vec<T>) {
+    let offset = runtime_array_offset::<H, T>();
+    assert!(bytes.len() >= H::END, "not enough bytes for a std430 value");
+    // the header's end padding may be missing if the array starts inside it
+    let mut header = bytes[..bytes.len().min(size_of::<H::Padded>())].to_vec();
+    header.resize(size_of::<H::Padded>(), 0);
+    let array = bytes.get(offset..).unwrap_or_default()
+        .chunks_exact(size_of::<T::ArrayElement>())
+        .map(|element| T::from_std430_element(&read(element)))
+        .collect();
+    (H::from_std430_bytes(&header), array)
+}
+/// the glsl declaration of a `layout(std430) buffer` block with the members of `H` followed by a
+/// runtime sized array of `T` called `array_name`.
+pub fn glsl_buffer_block_with_array<H: Std430, T: Std430>(block_name: &str, array_name: &str) -> String {
+    let mut definitions = Vec::new();
+    T::glsl_structs(&mut definitions);
+    let array = glsl_member(&T::glsl_type(), array_name, &format!("[]{}", T::glsl_array_suffix()));
+    let block = glsl_buffer_block::<H>(block_name, &array);
+    // `H` may use the same structs as `T`
+    definitions.retain(|definition| !block.contains(definition.as_str()));
+    definitions.concat() + &block
+}
+
+macro_rules! impl_std430 {
+    ($($ty:ty, $glsl:literal, $align:expr $(, $element_padding:literal)?;)*) => {
+        $(
+            unsafe impl Std430 for $ty {
+                const ALIGN: usize = $align;
+                type Padded = $ty;
+                type ArrayElement = impl_std430!(@element $ty $(, $element_padding)?);
+                fn to_std430(&self) -> Self::Padded { *self }
+                fn from_std430(padded: &Self::Padded) -> Self { *padded }
+                fn to_std430_element(&self) -> Self::ArrayElement {
+                    impl_std430!(@to_element self $(, $element_padding)?)
+                }
+                fn from_std430_element(element: &Self::ArrayElement) -> Self {
+                    impl_std430!(@from_element element $(, $element_padding)?)
+                }
+                fn glsl_type() -> String { $glsl.to_owned() }
+            }
+        )*
+    };
+    (@element $ty:ty) => { $ty };
+    (@element $ty:ty, $padding:literal) => { WithPadding<$ty, $padding> };
+    (@to_element $value:ident) => { *$value };
+    (@to_element $value:ident, $padding:literal) => { WithPadding(*$value, [0; $padding]) };
+    (@from_element $element:ident) => { *$element };
+    (@from_element $element:ident, $padding:literal) => { $element.0 };
+}
+impl_std430!(
+    f32, "float", 4; i32, "int", 4; u32, "uint", 4; f64, "double", 8;
+    Vec2, "vec2", 8; Vec3, "vec3", 16, 4; Vec4, "vec4", 16;
+    IVec2, "ivec2", 8; IVec3, "ivec3", 16, 4; IVec4, "ivec4", 16;
+    UVec2, "uvec2", 8; UVec3, "uvec3", 16, 4; UVec4, "uvec4", 16;
+    DVec2, "dvec2", 16; DVec3, "dvec3", 32, 8; DVec4, "dvec4", 32;
+    Quat, "vec4", 16; DQuat, "dvec4", 32;
+    Mat2, "mat2", 8; Mat4, "mat4", 16; DMat2, "dmat2", 16; DMat4, "dmat4", 32;
+);
+macro_rules! impl_std430_padded_matrix {
+    ($($mat:ident, $column:ident, $n:literal, $glsl:literal, $align:expr;)*) => {
+        $(
+            unsafe impl Std430 for $mat {
+                const ALIGN: usize = $align;
+                type Padded = PaddedColumns<$column, $n>;
+                type ArrayElement = PaddedColumns<$column, $n>;
+                fn to_std430(&self) -> Self::Padded { (*self).into() }
+                fn from_std430(padded: &Self::Padded) -> Self { (*padded).into() }
+                fn to_std430_element(&self) -> Self::ArrayElement { (*self).into() }
+                fn from_std430_element(element: &Self::ArrayElement) -> Self { (*element).into() }
+                fn glsl_type() -> String { $glsl.to_owned() }
+            }
+        )*
+    };
+}
+impl_std430_padded_matrix!(
+    Mat3, Vec4, 3, "mat3", 16;
+    DMat3, DVec4, 3, "dmat3", 32;
+);
+unsafe impl<T: Std430, const N: usize> Std430 for [T; N] {
+    const ALIGN: usize = T::ALIGN;
+    type Padded = PaddedArray<T::ArrayElement, N>;
+    type ArrayElement = PaddedArray<T::ArrayElement, N>;
+    fn to_std430(&self) -> Self::Padded { PaddedArray(self.each_ref().map(T::to_std430_element)) }
+    fn from_std430(padded: &Self::Padded) -> Self { padded.0.each_ref().map(T::from_std430_element) }
+    fn to_std430_element(&self) -> Self::ArrayElement { self.to_std430() }
+    fn from_std430_element(element: &Self::ArrayElement) -> Self { Self::from_std430(element) }
+    fn glsl_type() -> String { T::glsl_type() }
+    fn glsl_array_suffix() -> String { format!("[{N}]{}", T::glsl_array_suffix()) }
+    fn glsl_structs(definitions: &mut Vec<String>) { T::glsl_structs(definitions) }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::mem::offset_of;
+    use super::*;
+    #[derive(Clone, Copy, Debug, PartialEq, Std430)]
+    pub struct Particle {
+        position: Vec3,
+        mass: f32,
+        velocity: Vec3,
+    }
+    #[derive(Clone, Copy, Debug, PartialEq, Std430)]
+    pub struct Simulation {
+        pub time: f32,
+        pub weights: [f32; 3],
+        pub gravity: Vec2,
+        pub rotation: Mat3,
+        pub bounds: [Vec3; 2],
+        pub spawn: Particle,
+        pub seed: u32,
+    }
+    fn simulation() -> Simulation {
+        Simulation {
+            time: 1.5, weights: [0.25, 0.5, 0.75], gravity: vec2(0.0, -9.8),
+            rotation: Mat3::from_scale(vec3(1.0, 2.0, 3.0)), bounds: [-Vec3::ONE, Vec3::ONE],
+            spawn: Particle { position: Vec3::Y, mass: 2.0, velocity: Vec3::X }, seed: 7
+        }
+    }
+    #[test]
+    fn std430_offsets() {
+        // a float can fill the space after a vec3, but the struct is rounded up to 16
+        assert_eq!(Particle::OFFSETS, [("position", 0), ("mass", 12), ("velocity", 16)]);
+        assert_eq!(Particle::END, 28);
+        assert_eq!(size_of::<Std430Particle>(), 32);
+        // unlike std140, float arrays are tightly packed
+        assert_eq!(offset_of!(Std430Simulation, weights), 4);
+        assert_eq!(offset_of!(Std430Simulation, gravity), 16);
+        assert_eq!(offset_of!(Std430Simulation, rotation), 32);
+        // vec3s in arrays are still aligned to 16
+        assert_eq!(offset_of!(Std430Simulation, bounds), 80);
+        assert_eq!(offset_of!(Std430Simulation, spawn), 112);
+        assert_eq!(offset_of!(Std430Simulation, seed), 144);
+        assert_eq!(Simulation::OFFSETS.len(), 7);
+        assert_eq!(Simulation::ALIGN, 16);
+        assert_eq!(size_of::<Std430Simulation>(), 160);
+        assert_eq!(size_of::<<[Vec2; 3] as Std430>::Padded>(), 24);
+        assert_eq!(size_of::<<[DVec3; 2] as Std430>::Padded>(), 64);
+    }
+    #[test]
+    fn std430_bytes() {
+        let bytes = simulation().to_std430_bytes();
+        let float = |offset: usize| f32::from_ne_bytes(bytes[offset..offset + 4].try_into().unwrap());
+        assert_eq!([float(0), float(4), float(12), float(20)], [1.5, 0.25, 0.75, -9.8]);
+        assert_eq!([float(32), float(52), float(72), float(80), float(96)], [1.0, 2.0, 3.0, -1.0, 1.0]);
+        assert_eq!([float(116), float(124), float(128)], [1.0, 2.0, 1.0]);
+        assert_eq!(u32::from_ne_bytes(bytes[144..148].try_into().unwrap()), 7);
+        assert_eq!(Simulation::from_std430_bytes(&bytes), simulation());
+    }
+    #[test]
+    fn runtime_array() {
+        let particles = [
+            Particle { position: Vec3::X, mass: 1.0, velocity: Vec3::ZERO },
+            Particle { position: Vec3::Y, mass: 2.0, velocity: Vec3::ONE },
+        ];
+        // `seed` ends at 148, so the particles start at the next multiple of 16
+        assert_eq!(runtime_array_offset::<Simulation, Particle>(), 160);
+        let bytes = to_std430_bytes_with_array(&simulation(), &particles);
+        assert_eq!(bytes.len(), 160 + 2 * 32);
+        assert_eq!(from_std430_bytes_with_array(&bytes), (simulation(), particles.to_vec()));
+        // the array can start in the padding at the end of the header
+        assert_eq!(runtime_array_offset::<Particle, f32>(), 28);
+        let bytes = to_std430_bytes_with_array(&particles[0], &[5.0f32, 6.0]);
+        assert_eq!(bytes.len(), 36);
+        assert_eq!(from_std430_bytes_with_array(&bytes), (particles[0], vec![5.0f32, 6.0]));
+        assert_eq!(from_std430_bytes_with_array::<Particle, f32>(&bytes[..28]), (particles[0], vec![]));
+    }
+    #[test]
+    fn std430_glsl() {
+        assert_eq!(glsl_buffer_block_with_array::<Simulation, Particle>("Particles", "particles"),
+"struct Particle {
+    vec3 position;
+    float mass;
+    vec3 velocity;
+};
+layout(std430) buffer Particles {
+    float time;
+    float weights[3];
+    vec2 gravity;
+    mat3 rotation;
+    vec3 bounds[2];
+    Particle spawn;
+    uint seed;
+    Particle particles[];
+};
+");
+    }
+}
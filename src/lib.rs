@@ -5,16 +5,35 @@ pub mod vert_types;
 pub mod quaternions;
 pub mod shaders;
 pub mod params;
+pub mod layout;
+pub mod format;
+pub mod colour;
+pub mod noise;
+pub mod curves;
+#[cfg(feature = "rand")]
+pub mod random;
+mod approx_eq;
+mod attributes;
+mod interop;
+#[cfg(test)]
+mod testing;
 pub use glium;
+// lets the derive macros refer to `glium_types` from inside this crate
+extern crate self as glium_types;
 
 pub mod prelude{
     pub use crate::{
-        vert_types::{VertexColour, Vertex, TextureCoords, Normal},
+        vert_types::{
+            VertexColour, Vertex, TextureCoords, Normal, PackedVertexColour, PackedTextureCoords, PackedNormal,
+            Int2101010RevNormal, OctahedralNormal
+        },
         vectors::*,
         matrices::*,
         params,
         quaternions::*,
+        colour::{Rgb, Rgba, Srgb, Srgba},
         shaders,
-        mesh
+        mesh,
+        assert_approx_eq
     };
 }
@@ -4,6 +4,10 @@ use super::{DMat3, DMat4};
 
 #[derive(Clone, Copy, PartialEq, Debug)]
 /// a matrix often used for transformations in glium.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[repr(C)]
 pub struct DMat2 {
     matrix: [[f64; 2]; 2]
 }
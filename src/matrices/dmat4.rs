@@ -6,6 +6,10 @@ use super::DMat2;
 
 #[derive(Clone, Copy, PartialEq, Debug)]
 /// a matrix often used for transformations in glium.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[repr(C)]
 pub struct DMat4 {
     matrix: [[f64; 4]; 4]
 }
@@ -463,7 +467,7 @@ fn test_from_inverse_transform() {
     let transform = DMat4::from_transform(pos, scale, rot);
     let inv_transform = DMat4::from_inverse_transform(pos, scale, rot);
     let result = transform * inv_transform;
-    assert!(eq_mats(DMat4::IDENTITY, result));
+    crate::assert_approx_eq!(DMat4::IDENTITY, result, epsilon = f64::EPSILON);
 }
 #[test]
 fn mat4_inverse() {
@@ -471,7 +475,7 @@ fn mat4_inverse() {
     let pos = dvec3(1.0, 2.0, 0.3);
     let scale = dvec3(1.1, 2.0, 3.9);
     let a = DMat4::from_transform(pos, scale, rot);
-    assert!(eq_mats(DMat4::IDENTITY, a.inverse() * a));
+    crate::assert_approx_eq!(DMat4::IDENTITY, a.inverse() * a, epsilon = f64::EPSILON);
 }
 #[test]
 fn test_transform(){
@@ -480,16 +484,5 @@ fn test_transform(){
     let scale = dvec3(1.1, 2.0, 3.9);
     let transform = DMat4::from_transform(pos, scale, rot);
     let result = DMat4::from_pos(pos) * DMat4::from_rot(rot) * DMat4::from_scale(scale);
-    assert!(eq_mats(transform, result))
-}
-#[allow(dead_code)]
-fn eq_mats(a: DMat4, b: DMat4) -> bool {
-    for x in 0..4 {
-        for y in 0..4 {
-            if f64::abs(a[x][y] - b[x][y]) > f64::EPSILON {
-                return false;
-            }
-        }
-    }
-    true
+    crate::assert_approx_eq!(transform, result, epsilon = f64::EPSILON);
 }
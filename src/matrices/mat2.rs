@@ -3,7 +3,11 @@ use crate::prelude::Vec2;
 use super::{Mat3, Mat4};
 
 #[derive(Clone, Copy, PartialEq, Debug)]
-///a matrix often used for transformations in glium.
+/// a matrix often used for transformations in glium.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[repr(C)]
 pub struct Mat2 {
     matrix: [[f32; 2]; 2]
 }
@@ -5,6 +5,10 @@ use super::Mat2;
 
 #[derive(Clone, Copy, PartialEq, Debug)]
 /// a matrix often used for transformations in glium
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[repr(C)]
 pub struct Mat3 {
     matrix: [[f32; 3]; 3]
 }
@@ -6,6 +6,10 @@ use super::Mat2;
 
 #[derive(Clone, Copy, PartialEq, Debug)]
 /// a matrix often used for transformations in glium
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[repr(C)]
 pub struct Mat4 {
     matrix: [[f32; 4]; 4]
 }
@@ -238,6 +242,7 @@ impl Mat4{
 
         b
     }
+    #[cfg(not(feature = "simd"))]
     pub fn scale(self, scalar: f32) -> Mat4 {
         let Mat4 { matrix: [
             [a, e, i, m],
@@ -339,6 +344,7 @@ impl std::ops::Rem<Mat4> for f32 {
     }
     type Output = Mat4;
 }
+#[cfg(not(feature = "simd"))]
 #[allow(clippy::needless_range_loop)] // in this case i think it looks nicer :)
 impl std::ops::Mul for Mat4{
     fn mul(self, rhs: Self) -> Self::Output {
@@ -370,6 +376,7 @@ impl AsUniformValue for Mat4{
         glium::uniforms::UniformValue::Mat4(self.matrix)
     }
 }
+#[cfg(not(feature = "simd"))]
 impl std::ops::Add for Mat4{
     fn add(self, rhs: Self) -> Self::Output {
         let a = self.matrix;
@@ -390,6 +397,7 @@ impl std::ops::AddAssign for Mat4{
         *self = *self + rhs
     }
 }
+#[cfg(not(feature = "simd"))]
 impl std::ops::Sub for Mat4{
     fn sub(self, rhs: Self) -> Self::Output {
         let a = self.matrix;
@@ -456,6 +464,7 @@ impl std::ops::IndexMut<usize> for Mat4{
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::assert_approx_eq;
     #[test]
     fn test_from_inverse_transform() {
         let rot = Quat::from_x_rot(1.3);
@@ -464,7 +473,7 @@ mod tests {
         let transform = Mat4::from_transform(pos, scale, rot);
         let inv_transform = Mat4::from_inverse_transform(pos, scale, rot);
         let result = transform * inv_transform;
-        assert!(eq_mats(Mat4::IDENTITY, result));
+        assert_approx_eq!(Mat4::IDENTITY, result, epsilon = f32::EPSILON);
     }
     #[test]
     fn mat4_inverse() {
@@ -472,7 +481,7 @@ mod tests {
         let pos = vec3(1.0, 2.0, 0.3);
         let scale = vec3(1.1, 2.0, 3.9);
         let a = Mat4::from_transform(pos, scale, rot);
-        assert!(eq_mats(Mat4::IDENTITY, a.inverse() * a));
+        assert_approx_eq!(Mat4::IDENTITY, a.inverse() * a, epsilon = f32::EPSILON);
     }
     #[test]
     fn test_transform(){
@@ -481,16 +490,6 @@ mod tests {
         let scale = vec3(1.1, 2.0, 3.9);
         let transform = Mat4::from_transform(pos, scale, rot);
         let result = Mat4::from_pos(pos) * Mat4::from_rot(rot) * Mat4::from_scale(scale);
-        assert!(eq_mats(transform, result))
-    }
-    fn eq_mats(a: Mat4, b: Mat4) -> bool {
-        for x in 0..4 {
-            for y in 0..4 {
-                if f32::abs(a[x][y] - b[x][y]) > f32::EPSILON {
-                    return false;
-                }
-            }
-        }
-        true
+        assert_approx_eq!(transform, result, epsilon = f32::EPSILON);
     }
 }
@@ -10,3 +10,28 @@ mod dmat4;
 pub use dmat3::DMat3;
 pub use dmat4::DMat4;
 pub use dmat2::DMat2;
+
+#[cfg(all(test, feature = "serde"))]
+mod serde_tests {
+    use super::*;
+    #[test]
+    fn json() {
+        use crate::{testing::serde_round_trip, vectors::vec3};
+        let a = Mat3::from_values(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+        serde_round_trip(a, "[[1.0,4.0,7.0],[2.0,5.0,8.0],[3.0,6.0,9.0]]");
+        serde_round_trip(DMat2::IDENTITY, "[[1.0,0.0],[0.0,1.0]]");
+        let m = Mat4::from_pos(vec3(1.0, 2.0, 3.0));
+        assert_eq!(serde_json::from_str::<Mat4>(&serde_json::to_string(&m).unwrap()).unwrap(), m);
+    }
+}
+
+#[cfg(all(test, feature = "bytemuck"))]
+mod bytemuck_tests {
+    use super::*;
+    #[test]
+    fn casting() {
+        let m = Mat4::from_pos(crate::vectors::vec3(1.0, 2.0, 3.0));
+        let columns: [[f32; 4]; 4] = bytemuck::cast(m);
+        assert_eq!(columns, m.into_column_major_array());
+    }
+}
@@ -0,0 +1,76 @@
+//! noise on a square grid of cells, which can be tiled. the loops over the corners of a cell are the
+//! same as the glsl versions, so the sums are done in the same order.
+use std::array::from_fn;
+use super::*;
+
+/// scales perlin noise to -1.0 to 1.0. the range of perlin noise with gradients of length 1 is
+/// `sqrt(n) / 2`, and the gradients are `sqrt(5)`, `sqrt(2)` and `sqrt(3)` long.
+fn perlin_scale(n: usize) -> f32 {
+    match n {
+        2 => 0.6324555,
+        3 => 0.8164966,
+        _ => 0.5773503
+    }
+}
+/// the quintic curve that blends between corners, and its derivative.
+fn fade(t: f32) -> (f32, f32) {
+    (t * t * t * (t * (t * 6.0 - 15.0) + 10.0), 30.0 * t * t * (t * (t - 2.0) + 1.0))
+}
+/// calls `corner` with each corner of the cell and its weight. returns the sum of `corner` times the
+/// weight and the derivative of it, given `corner` also returns the derivative of its value.
+fn blend<const N: usize>(f: [f32; N], mut corner: impl FnMut([f32; N]) -> (f32, [f32; N])) -> (f32, [f32; N]) {
+    let (u, du): ([f32; N], [f32; N]) = (from_fn(|i| fade(f[i]).0), from_fn(|i| fade(f[i]).1));
+    let (mut value, mut gradient) = (0.0, [0.0; N]);
+    for c in 0..1 << N {
+        let o: [f32; N] = from_fn(|i| (c >> i & 1) as f32);
+        let (n, g) = corner(o);
+        let w: [f32; N] = from_fn(|i| if o[i] == 1.0 { u[i] } else { 1.0 - u[i] });
+        let dw: [f32; N] = from_fn(|i| if o[i] == 1.0 { du[i] } else { -du[i] });
+        let weight = w.iter().product::<f32>();
+        value += weight * n;
+        for i in 0..N {
+            let partial = (0..N).map(|j| if i == j { dw[j] } else { w[j] }).product::<f32>();
+            gradient[i] += weight * g[i] + n * partial;
+        }
+    }
+    (value, gradient)
+}
+pub fn perlin<const N: usize>(p: [f32; N], period: Option<[f32; N]>) -> (f32, [f32; N]) {
+    let i = p.map(f32::floor);
+    let f: [f32; N] = from_fn(|j| p[j] - i[j]);
+    let (n, g) = blend(f, |o| {
+        let g = gradient::<N>(hash_cell(wrap(from_fn(|j| i[j] + o[j]), period)));
+        (dot(g, from_fn(|j| f[j] - o[j])), g)
+    });
+    (n * perlin_scale(N), g.map(|g| g * perlin_scale(N)))
+}
+pub fn value<const N: usize>(p: [f32; N], period: Option<[f32; N]>) -> (f32, [f32; N]) {
+    let i = p.map(f32::floor);
+    let f: [f32; N] = from_fn(|j| p[j] - i[j]);
+    blend(f, |o| (unit(hash_cell(wrap(from_fn(|j| i[j] + o[j]), period))) * 2.0 - 1.0, [0.0; N]))
+}
+pub fn worley<const N: usize>(p: [f32; N], period: Option<[f32; N]>) -> (f32, [f32; N]) {
+    let i = p.map(f32::floor);
+    let f: [f32; N] = from_fn(|j| p[j] - i[j]);
+    let (mut closest, mut direction) = (f32::INFINITY, [0.0; N]);
+    // only the 3^N cells around p are searched. a point two cells away can rarely be closer than all
+    // of them, which leaves a small crease in the noise, but it is the same on the cpu and gpu
+    for c in 0..3usize.pow(N as u32) {
+        let o: [f32; N] = from_fn(|j| (c / 3usize.pow(j as u32) % 3) as f32 - 1.0);
+        let mut h = hash_cell(wrap(from_fn(|j| i[j] + o[j]), period));
+        let mut point = [0.0; N];
+        for j in 0..N {
+            point[j] = o[j] + unit(h);
+            h = hash(h);
+        }
+        let d: [f32; N] = from_fn(|j| point[j] - f[j]);
+        let distance = dot(d, d);
+        if distance < closest {
+            closest = distance;
+            direction = d;
+        }
+    }
+    let distance = closest.sqrt();
+    if distance == 0.0 { return (0.0, [0.0; N]) }
+    (distance, direction.map(|d| -d / distance))
+}
@@ -0,0 +1,315 @@
+//! noise functions over `Vec2`, `Vec3` and `Vec4`, for terrain, textures and animated effects.
+//! every function has a glsl version with the same name in `shaders::NOISE`, which uses the same
+//! hash and maths, so noise made on the cpu lines up with noise made in a shader.
+//! - `perlin_noise` and `simplex_noise` are smooth gradient noise from -1.0 to 1.0. simplex is
+//!   faster in 3d and 4d and doesn't line up with the axes.
+//! - `value_noise` smoothly blends random values from -1.0 to 1.0, which looks blockier.
+//! - `worley_noise` is the distance to the closest of a set of random points, with one point in each
+//!   cell. it is 0.0 at the points and usually less than 1.0. only the neighbouring cells are
+//!   searched, so very rarely it uses a point that isn't quite the closest.
+//!
+//! the `_gradient` functions also return the exact derivative of the noise, e.g for terrain normals.
+//! the `tiled_` functions repeat every `period` cells, where each component of `period` is a whole
+//! number. simplex noise isn't on a square grid, so it can't be tiled. `fbm`, `ridged` and
+//! `turbulence` add octaves of any of them together.
+//! ```
+//! use glium_types::{prelude::*, noise::*};
+//! let height = fbm(vec2(3.2, 1.7), 5, 2.0, 0.5, perlin_noise);
+//! assert!(height.abs() <= 1.0);
+//! let (density, gradient) = simplex_noise_gradient(vec3(0.5, 2.0, -1.0));
+//! // the same as the glsl `simplex_noise_gradient(p, gradient)`
+//! let tile = tiled_worley_noise(vec2(0.25, 7.25), vec2(4.0, 4.0));
+//! assert!(tile == tiled_worley_noise(vec2(4.25, 3.25), vec2(4.0, 4.0)));
+//! ```
+use std::ops::{Add, Mul};
+use crate::vectors::*;
+mod lattice;
+mod simplex;
+
+/// a 32 bit integer hash, the lowbias32 hash by chris wellons.
+fn hash(mut x: u32) -> u32 {
+    x ^= x >> 16;
+    x = x.wrapping_mul(0x7feb352d);
+    x ^= x >> 15;
+    x = x.wrapping_mul(0x846ca68b);
+    x ^= x >> 16;
+    x
+}
+/// hashes the integer coordinates of a cell, starting from the last component.
+fn hash_cell<const N: usize>(cell: [f32; N]) -> u32 {
+    cell.iter().rev().fold(0, |h, &c| hash(c as i32 as u32 ^ h))
+}
+/// a float from 0.0 to 1.0 using the top 24 bits of `h`, so it is exact.
+fn unit(h: u32) -> f32 { (h >> 8) as f32 * (1.0 / 16777216.0) }
+/// one of a set of gradients with the same length, picked by `h`. these are perlin's edge gradients
+/// in 3d, and stefan gustavson's gradients in 2d and 4d.
+fn gradient<const N: usize>(h: u32) -> [f32; N] {
+    let sign = |bit: u32| if h & bit == 0 { 1.0 } else { -1.0 };
+    let mut g = [0.0; N];
+    match N {
+        2 => {
+            let h = h & 7;
+            let u = if h < 4 { 0 } else { 1 };
+            g[u] = sign(1);
+            g[1 - u] = sign(2) * 2.0;
+        },
+        3 => {
+            let h = h & 15;
+            let u = if h < 8 { 0 } else { 1 };
+            let v = if h < 4 { 1 } else if h == 12 || h == 14 { 0 } else { 2 };
+            g[u] = sign(1);
+            g[v] = sign(2);
+        },
+        _ => {
+            let h = h & 31;
+            let u = if h < 24 { 0 } else { 1 };
+            let v = if h < 16 { 1 } else { 2 };
+            let w = if h < 8 { 2 } else { 3 };
+            g[u] = sign(1);
+            g[v] = sign(2);
+            g[w] = sign(4);
+        }
+    }
+    g
+}
+fn dot<const N: usize>(a: [f32; N], b: [f32; N]) -> f32 { (0..N).map(|i| a[i] * b[i]).sum() }
+/// the same as glsl `mod`, so negative cells wrap the same way in both.
+fn wrap<const N: usize>(cell: [f32; N], period: Option<[f32; N]>) -> [f32; N] {
+    match period {
+        Some(period) => std::array::from_fn(|i| cell[i] - period[i] * (cell[i] / period[i]).floor()),
+        None => cell
+    }
+}
+
+mod private {
+    pub trait Sealed {}
+}
+/// the vectors noise can be made from: `Vec2`, `Vec3` and `Vec4`.
+pub trait NoiseInput: Copy + private::Sealed {
+    #[doc(hidden)]
+    fn perlin(self, period: Option<Self>) -> (f32, Self);
+    #[doc(hidden)]
+    fn simplex(self) -> (f32, Self);
+    #[doc(hidden)]
+    fn value(self, period: Option<Self>) -> (f32, Self);
+    #[doc(hidden)]
+    fn worley(self, period: Option<Self>) -> (f32, Self);
+}
+macro_rules! impl_noise_input {
+    ($($vec:ident, $n:literal;)*) => {
+        $(
+            impl private::Sealed for $vec {}
+            impl NoiseInput for $vec {
+                fn perlin(self, period: Option<Self>) -> (f32, Self) {
+                    let (n, g) = lattice::perlin::<$n>(self.into(), period.map(Into::into));
+                    (n, g.into())
+                }
+                fn simplex(self) -> (f32, Self) {
+                    let (n, g) = simplex::simplex::<$n>(self.into());
+                    (n, g.into())
+                }
+                fn value(self, period: Option<Self>) -> (f32, Self) {
+                    let (n, g) = lattice::value::<$n>(self.into(), period.map(Into::into));
+                    (n, g.into())
+                }
+                fn worley(self, period: Option<Self>) -> (f32, Self) {
+                    let (n, g) = lattice::worley::<$n>(self.into(), period.map(Into::into));
+                    (n, g.into())
+                }
+            }
+        )*
+    };
+}
+impl_noise_input!(Vec2, 2; Vec3, 3; Vec4, 4;);
+
+/// perlin gradient noise from -1.0 to 1.0.
+pub fn perlin_noise<V: NoiseInput>(p: V) -> f32 { p.perlin(None).0 }
+/// perlin noise and its derivative.
+pub fn perlin_noise_gradient<V: NoiseInput>(p: V) -> (f32, V) { p.perlin(None) }
+/// perlin noise that repeats every `period` cells.
+pub fn tiled_perlin_noise<V: NoiseInput>(p: V, period: V) -> f32 { p.perlin(Some(period)).0 }
+/// perlin noise that repeats every `period` cells, and its derivative.
+pub fn tiled_perlin_noise_gradient<V: NoiseInput>(p: V, period: V) -> (f32, V) { p.perlin(Some(period)) }
+/// simplex gradient noise from -1.0 to 1.0.
+pub fn simplex_noise<V: NoiseInput>(p: V) -> f32 { p.simplex().0 }
+/// simplex noise and its derivative.
+pub fn simplex_noise_gradient<V: NoiseInput>(p: V) -> (f32, V) { p.simplex() }
+/// value noise from -1.0 to 1.0.
+pub fn value_noise<V: NoiseInput>(p: V) -> f32 { p.value(None).0 }
+/// value noise and its derivative.
+pub fn value_noise_gradient<V: NoiseInput>(p: V) -> (f32, V) { p.value(None) }
+/// value noise that repeats every `period` cells.
+pub fn tiled_value_noise<V: NoiseInput>(p: V, period: V) -> f32 { p.value(Some(period)).0 }
+/// value noise that repeats every `period` cells, and its derivative.
+pub fn tiled_value_noise_gradient<V: NoiseInput>(p: V, period: V) -> (f32, V) { p.value(Some(period)) }
+/// the distance to the closest random point.
+pub fn worley_noise<V: NoiseInput>(p: V) -> f32 { p.worley(None).0 }
+/// worley noise and its derivative, which is the direction away from the closest point.
+pub fn worley_noise_gradient<V: NoiseInput>(p: V) -> (f32, V) { p.worley(None) }
+/// worley noise that repeats every `period` cells.
+pub fn tiled_worley_noise<V: NoiseInput>(p: V, period: V) -> f32 { p.worley(Some(period)).0 }
+/// worley noise that repeats every `period` cells, and its derivative.
+pub fn tiled_worley_noise_gradient<V: NoiseInput>(p: V, period: V) -> (f32, V) { p.worley(Some(period)) }
+
+/// fractal brownian motion. adds `octaves` layers of `noise`, each with the frequency multiplied by
+/// `lacunarity` and the amplitude multiplied by `gain`, then divides by the total amplitude so it has
+/// the same range as `noise`. 0 octaves is treated as 1, the same as in glsl. tiled noise stays
+/// tiled when `lacunarity` is a whole number.
+pub fn fbm<V: Copy + Mul<f32, Output = V>>(
+    p: V, octaves: u32, lacunarity: f32, gain: f32, noise: impl Fn(V) -> f32
+) -> f32 {
+    octaves_of(p, octaves, lacunarity, gain, noise)
+}
+/// like `fbm`, but each octave is `(1 - |noise|)^2`, which makes sharp ridges where the noise is
+/// 0.0. the result is from 0.0 to 1.0 when `noise` is from -1.0 to 1.0.
+pub fn ridged<V: Copy + Mul<f32, Output = V>>(
+    p: V, octaves: u32, lacunarity: f32, gain: f32, noise: impl Fn(V) -> f32
+) -> f32 {
+    octaves_of(p, octaves, lacunarity, gain, |p| {
+        let n = 1.0 - noise(p).abs();
+        n * n
+    })
+}
+/// like `fbm`, but each octave is `|noise|`, which makes billowy creases where the noise is 0.0.
+/// the result is from 0.0 to 1.0 when `noise` is from -1.0 to 1.0.
+pub fn turbulence<V: Copy + Mul<f32, Output = V>>(
+    p: V, octaves: u32, lacunarity: f32, gain: f32, noise: impl Fn(V) -> f32
+) -> f32 {
+    octaves_of(p, octaves, lacunarity, gain, |p| noise(p).abs())
+}
+/// `fbm` of a noise that returns its derivative, e.g `perlin_noise_gradient`, and the derivative of
+/// the result. 0 octaves is treated as 1.
+pub fn fbm_gradient<V: Copy + Add<Output = V> + Mul<f32, Output = V>>(
+    p: V, octaves: u32, lacunarity: f32, gain: f32, noise: impl Fn(V) -> (f32, V)
+) -> (f32, V) {
+    let (n, g) = noise(p);
+    let (mut sum, mut gradient, mut total) = (n, g, 1.0);
+    let (mut p, mut amplitude, mut frequency) = (p * lacunarity, gain, lacunarity);
+    for _ in 1..octaves {
+        let (n, g) = noise(p);
+        sum += amplitude * n;
+        gradient = gradient + g * (amplitude * frequency);
+        total += amplitude;
+        p = p * lacunarity;
+        amplitude *= gain;
+        frequency *= lacunarity;
+    }
+    (sum / total, gradient * (1.0 / total))
+}
+fn octaves_of<V: Copy + Mul<f32, Output = V>>(
+    mut p: V, octaves: u32, lacunarity: f32, gain: f32, noise: impl Fn(V) -> f32
+) -> f32 {
+    let (mut sum, mut amplitude, mut total) = (0.0, 1.0, 0.0);
+    for _ in 0..octaves.max(1) {
+        sum += amplitude * noise(p);
+        total += amplitude;
+        p = p * lacunarity;
+        amplitude *= gain;
+    }
+    sum / total
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    /// points spread over a few cells, including negative ones.
+    fn points<V: From<[f32; N]>, const N: usize>(count: usize) -> impl Iterator<Item = V> {
+        let mut h = 1;
+        (0..count).map(move |_| V::from(std::array::from_fn(|_| {
+            h = hash(h);
+            unit(h) * 8.0 - 4.0
+        })))
+    }
+    /// checks the gradient against the change in the noise over a small step in each direction.
+    fn check_gradient<V, const N: usize>(noise: impl Fn(V) -> (f32, V), tolerance: f32)
+    where V: NoiseInput + From<[f32; N]> + Into<[f32; N]> {
+        const STEP: f32 = 1e-3;
+        for p in points::<V, N>(500) {
+            let (_, gradient) = noise(p);
+            let [p, gradient]: [[f32; N]; 2] = [p.into(), gradient.into()];
+            for i in 0..N {
+                let [mut a, mut b] = [p, p];
+                a[i] -= STEP;
+                b[i] += STEP;
+                let slope = (noise(V::from(b)).0 - noise(V::from(a)).0) / (2.0 * STEP);
+                assert!((slope - gradient[i]).abs() < tolerance, "{p:?} axis {i}: {slope} {}", gradient[i]);
+            }
+        }
+    }
+    #[test]
+    fn ranges() {
+        fn check<V: NoiseInput + From<[f32; N]>, const N: usize>() {
+            for p in points::<V, N>(5000) {
+                assert!(perlin_noise(p).abs() <= 1.0 && simplex_noise(p).abs() <= 1.0 && value_noise(p).abs() <= 1.0);
+                assert!((0.0..1.5).contains(&worley_noise(p)));
+            }
+            // the noise should use most of its range
+            let max = points::<V, N>(5000).map(|p| perlin_noise(p).abs().max(simplex_noise(p).abs())).fold(0.0, f32::max);
+            assert!(max > 0.6, "{max}");
+        }
+        check::<Vec2, 2>();
+        check::<Vec3, 3>();
+        check::<Vec4, 4>();
+        // gradient noise is 0.0 at the corners of cells
+        assert!(perlin_noise(vec3(3.0, -2.0, 7.0)) == 0.0 && simplex_noise(Vec2::ZERO) == 0.0);
+    }
+    #[test]
+    fn gradients() {
+        check_gradient::<Vec2, 2>(perlin_noise_gradient, 2e-2);
+        check_gradient::<Vec3, 3>(perlin_noise_gradient, 2e-2);
+        check_gradient::<Vec4, 4>(perlin_noise_gradient, 2e-2);
+        check_gradient::<Vec2, 2>(simplex_noise_gradient, 5e-2);
+        check_gradient::<Vec3, 3>(simplex_noise_gradient, 5e-2);
+        check_gradient::<Vec4, 4>(simplex_noise_gradient, 5e-2);
+        check_gradient::<Vec3, 3>(value_noise_gradient, 2e-2);
+        check_gradient::<Vec4, 4>(|p| tiled_value_noise_gradient(p, Vec4::splat(3.0)), 2e-2);
+        check_gradient::<Vec2, 2>(|p| tiled_perlin_noise_gradient(p, vec2(2.0, 5.0)), 2e-2);
+        // worley noise has creases between points, so only check that the gradient is a direction
+        for p in points::<Vec3, 3>(500) {
+            assert!((worley_noise_gradient(p).1.length() - 1.0).abs() < 1e-4);
+        }
+        let p = vec2(0.3, 0.6);
+        let (n, g) = fbm_gradient(p, 4, 2.0, 0.5, perlin_noise_gradient);
+        let step = 1e-3;
+        let slope = (fbm(p + vec2(step, 0.0), 4, 2.0, 0.5, perlin_noise) - fbm(p - vec2(step, 0.0), 4, 2.0, 0.5, perlin_noise)) / (2.0 * step);
+        assert!(n == fbm(p, 4, 2.0, 0.5, perlin_noise) && (slope - g.x).abs() < 2e-2, "{slope} {g:?}");
+    }
+    #[test]
+    fn tiling() {
+        let period = vec3(4.0, 2.0, 3.0);
+        for p in points::<Vec3, 3>(500) {
+            for offset in [vec3(4.0, 0.0, 0.0), vec3(0.0, -2.0, 0.0), vec3(-8.0, 6.0, 9.0)] {
+                assert!((tiled_perlin_noise(p, period) - tiled_perlin_noise(p + offset, period)).abs() < 1e-4);
+                assert!((tiled_value_noise(p, period) - tiled_value_noise(p + offset, period)).abs() < 1e-4);
+                assert!((tiled_worley_noise(p, period) - tiled_worley_noise(p + offset, period)).abs() < 1e-4);
+            }
+        }
+        // octaves with a whole number lacunarity stay tiled
+        let noise = |p| fbm(p, 3, 2.0, 0.5, |p| tiled_perlin_noise(p, vec2(3.0, 3.0)));
+        assert!((noise(vec2(0.4, 0.2)) - noise(vec2(3.4, -2.8))).abs() < 1e-4);
+        // and the untiled noise doesn't repeat
+        assert!(perlin_noise(vec3(0.5, 0.5, 0.5)) != perlin_noise(vec3(4.5, 0.5, 0.5)));
+    }
+    #[test]
+    fn octaves() {
+        let p = vec4(0.1, 0.2, 0.3, 0.4);
+        assert!(fbm(p, 1, 2.0, 0.5, simplex_noise) == simplex_noise(p));
+        assert!(fbm(p, 0, 2.0, 0.5, simplex_noise) == simplex_noise(p));
+        assert!(ridged(p, 0, 2.0, 0.5, value_noise) == ridged(p, 1, 2.0, 0.5, value_noise));
+        assert!(turbulence(p, 0, 2.0, 0.5, perlin_noise) == perlin_noise(p).abs());
+        assert!(fbm_gradient(p, 0, 2.0, 0.5, perlin_noise_gradient) == perlin_noise_gradient(p));
+        let two = (value_noise(p) + 0.5 * value_noise(p * 2.0)) / 1.5;
+        assert!((fbm(p, 2, 2.0, 0.5, value_noise) - two).abs() < 1e-6);
+        for p in points::<Vec2, 2>(500) {
+            assert!((0.0..=1.0).contains(&ridged(p, 5, 2.0, 0.5, perlin_noise)));
+            assert!((0.0..=1.0).contains(&turbulence(p, 5, 2.0, 0.5, simplex_noise)));
+        }
+        // known values, so changes to the hash or maths that would stop it matching glsl are noticed
+        assert!(value_noise(vec2(0.0, 0.0)) == unit(hash_cell([0.0, 0.0])) * 2.0 - 1.0);
+        assert!(hash(1) == 0x688990c0 && hash_cell([-1.0, 2.0, 3.0]) == 0xcf44c0bc);
+        assert!((perlin_noise(vec3(0.25, 0.5, 0.75)) - 0.020758612).abs() < 1e-6);
+        assert!((simplex_noise(vec2(0.3, -1.2)) - 0.277114).abs() < 1e-6);
+        assert!((value_noise(vec2(0.5, 0.5)) + 0.21122515).abs() < 1e-6);
+        assert!((worley_noise(vec4(0.5, 1.5, 2.5, -0.5)) - 0.3586446).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,39 @@
+//! simplex noise, which sums the gradients of the n + 1 corners of the simplex `p` is in instead of
+//! the 2^n corners of a square cell.
+use std::array::from_fn;
+use super::*;
+
+/// the constants that skew the grid of simplices onto a square grid and back, and the scale that
+/// puts the noise in -1.0 to 1.0, found by searching for the largest value.
+fn constants(n: usize) -> (f32, f32, f32) {
+    match n {
+        2 => (0.3660254, 0.21132487, 45.0),
+        3 => (1.0 / 3.0, 1.0 / 6.0, 76.5),
+        _ => (0.309017, 0.1381966, 62.0)
+    }
+}
+pub fn simplex<const N: usize>(p: [f32; N]) -> (f32, [f32; N]) {
+    let (skew, unskew, scale) = constants(N);
+    let s = p.iter().sum::<f32>() * skew;
+    let i: [f32; N] = from_fn(|j| (p[j] + s).floor());
+    let t = i.iter().sum::<f32>() * unskew;
+    let x0: [f32; N] = from_fn(|j| p[j] - i[j] + t);
+    // the order of the components decides which corners of the square cell are in the simplex
+    let rank: [usize; N] = from_fn(|j| (0..N).filter(|&k| x0[j] > x0[k] || (x0[j] == x0[k] && j > k)).count());
+    let (mut value, mut gradient) = (0.0, [0.0; N]);
+    for c in 0..=N {
+        let o: [f32; N] = from_fn(|j| if rank[j] + c >= N { 1.0 } else { 0.0 });
+        let d: [f32; N] = from_fn(|j| x0[j] - o[j] + c as f32 * unskew);
+        let t = 0.5 - dot(d, d);
+        if t > 0.0 {
+            let g = super::gradient::<N>(hash_cell::<N>(from_fn(|j| i[j] + o[j])));
+            let gd = dot(g, d);
+            let (t2, t4) = (t * t, t * t * t * t);
+            value += t4 * gd;
+            for j in 0..N {
+                gradient[j] += t4 * g[j] - 8.0 * t2 * t * gd * d[j];
+            }
+        }
+    }
+    (value * scale, gradient.map(|g| g * scale))
+}
@@ -5,6 +5,9 @@ use crate::vectors::{Vec3, Vec4, DVec4, DVec3};
 #[derive(Clone, Copy, CmpAdd, CmpSub, CmpAddAssign, CmpSubAssign, CmpNeg, Debug, PartialEq)]
 /// a 4 part vector often used to represent rotations. note that multiplication of quaternions
 /// is applying transformations
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[repr(C)]
 pub struct Quat{
     pub r: f32,
     pub i: f32,
@@ -155,6 +158,9 @@ fn quaternion_inverse() {
 #[derive(Clone, Copy, CmpAdd, CmpSub, CmpAddAssign, CmpSubAssign, CmpNeg, Debug, PartialEq)]
 /// a 4 part double vector often used to represent rotations. note that multiplication of quaternions
 /// is applying transformations
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[repr(C)]
 pub struct DQuat {
     pub r: f64,
     pub i: f64,
@@ -302,3 +308,22 @@ impl std::ops::Div for DQuat {
     type Output = Self;
 }
 impl std::ops::DivAssign for DQuat { fn div_assign(&mut self, rhs: Self) { *self = *self / rhs } }
+
+#[cfg(all(test, feature = "serde"))]
+mod serde_tests {
+    use super::*;
+    #[test]
+    fn json() {
+        crate::testing::serde_round_trip(Quat::IDENTITY, r#"{"r":1.0,"i":0.0,"j":0.0,"k":0.0}"#);
+    }
+}
+
+#[cfg(all(test, feature = "bytemuck"))]
+mod bytemuck_tests {
+    use super::*;
+    #[test]
+    fn casting() {
+        let q: [f32; 4] = bytemuck::cast(Quat::IDENTITY);
+        assert_eq!(q, [1.0, 0.0, 0.0, 0.0]);
+    }
+}
@@ -0,0 +1,369 @@
+//! `rand` distributions for vectors and rotations, and for points in or on common shapes.
+//! - `StandardUniform` gives each component of a vector the same as the scalar would, so float
+//!   vectors are from 0.0 to 1.0, and gives quaternions a uniformly random rotation.
+//! - vectors implement `SampleUniform`, so `rng.random_range(min..max)` or `Uniform::new(min, max)`
+//!   gives points in the box between `min` and `max`.
+//! - `UnitCircle`, `UnitDisk`, `UnitSphere`, `UnitBall`, `Hemisphere`, `CosineHemisphere` and `Cone`
+//!   give points on or in those shapes with the same density everywhere, except `CosineHemisphere`
+//!   which is denser near its normal the same way diffuse light is.
+//! - `Triangle` and `MeshSurface` give points with the same density over their surface.
+//! ```
+//! use rand::{Rng, SeedableRng, rngs::StdRng};
+//! use glium_types::{prelude::*, random::*};
+//! let mut rng = StdRng::seed_from_u64(1);
+//! let spawn = rng.random_range(vec3(-1.0, 0.0, -1.0)..vec3(1.0, 2.0, 1.0));
+//! let velocity = rng.sample(Cone::new(Vec3::Y, 0.3)) * 5.0;
+//! let rotation: Quat = rng.random();
+//! let teapot = MeshSurface::new(&glium_types::teapot::VERTICES, &glium_types::teapot::INDICES).unwrap();
+//! let on_teapot = rng.sample(&teapot);
+//! assert!(velocity.y > 4.0 && spawn.y >= 0.0);
+//! ```
+use std::f32::consts::TAU;
+use rand::{Rng, distr::{Distribution, StandardUniform, Uniform, uniform::{self, SampleBorrow, SampleUniform, UniformSampler}}};
+use crate::{vectors::*, quaternions::*};
+
+macro_rules! impl_standard {
+    ($($vec:ident { $($c:ident)* }),* $(,)?) => {
+        $(
+            impl Distribution<$vec> for StandardUniform {
+                fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $vec { $vec { $($c: rng.random()),* } }
+            }
+        )*
+    };
+}
+impl_standard!(
+    Vec2 { x y }, Vec3 { x y z }, Vec4 { x y z w }, DVec2 { x y }, DVec3 { x y z }, DVec4 { x y z w },
+    IVec2 { x y }, IVec3 { x y z }, IVec4 { x y z w }, UVec2 { x y }, UVec3 { x y z }, UVec4 { x y z w },
+    DIVec2 { x y }, DIVec3 { x y z }, DIVec4 { x y z w }, DUVec2 { x y }, DUVec3 { x y z }, DUVec4 { x y z w },
+    BVec2 { x y }, BVec3 { x y z }, BVec4 { x y z w },
+);
+macro_rules! impl_sample_uniform {
+    ($($vec:ident, $sampler:ident, $scalar:ty { $($c:ident)* };)*) => {
+        $(
+            #[derive(Clone, Copy, Debug, PartialEq)]
+            #[doc = concat!("samples each component of a `", stringify!($vec), "` separately, giving points in a box.")]
+            pub struct $sampler { $($c: Uniform<$scalar>),* }
+            impl UniformSampler for $sampler {
+                type X = $vec;
+                /// fails unless every component of `low` is less than `high`.
+                fn new<B1, B2>(low: B1, high: B2) -> Result<Self, uniform::Error>
+                where B1: SampleBorrow<$vec> + Sized, B2: SampleBorrow<$vec> + Sized {
+                    let (low, high) = (low.borrow(), high.borrow());
+                    Ok(Self { $($c: Uniform::new(low.$c, high.$c)?),* })
+                }
+                fn new_inclusive<B1, B2>(low: B1, high: B2) -> Result<Self, uniform::Error>
+                where B1: SampleBorrow<$vec> + Sized, B2: SampleBorrow<$vec> + Sized {
+                    let (low, high) = (low.borrow(), high.borrow());
+                    Ok(Self { $($c: Uniform::new_inclusive(low.$c, high.$c)?),* })
+                }
+                fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $vec { $vec { $($c: self.$c.sample(rng)),* } }
+            }
+            impl SampleUniform for $vec {
+                type Sampler = $sampler;
+            }
+        )*
+    };
+}
+impl_sample_uniform!(
+    Vec2, UniformVec2, f32 { x y }; Vec3, UniformVec3, f32 { x y z }; Vec4, UniformVec4, f32 { x y z w };
+    DVec2, UniformDVec2, f64 { x y }; DVec3, UniformDVec3, f64 { x y z }; DVec4, UniformDVec4, f64 { x y z w };
+    IVec2, UniformIVec2, i32 { x y }; IVec3, UniformIVec3, i32 { x y z }; IVec4, UniformIVec4, i32 { x y z w };
+    UVec2, UniformUVec2, u32 { x y }; UVec3, UniformUVec3, u32 { x y z }; UVec4, UniformUVec4, u32 { x y z w };
+);
+
+/// a uniformly random rotation, using ken shoemake's method.
+impl Distribution<Quat> for StandardUniform {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Quat {
+        let (u, a, b): (f32, f32, f32) = (rng.random(), rng.random::<f32>() * TAU, rng.random::<f32>() * TAU);
+        let (s, t) = ((1.0 - u).sqrt(), u.sqrt());
+        Quat { r: t * b.cos(), i: s * a.sin(), j: s * a.cos(), k: t * b.sin() }
+    }
+}
+/// a uniformly random rotation, using ken shoemake's method.
+impl Distribution<DQuat> for StandardUniform {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> DQuat {
+        let tau = std::f64::consts::TAU;
+        let (u, a, b): (f64, f64, f64) = (rng.random(), rng.random::<f64>() * tau, rng.random::<f64>() * tau);
+        let (s, t) = ((1.0 - u).sqrt(), u.sqrt());
+        DQuat { r: t * b.cos(), i: s * a.sin(), j: s * a.cos(), k: t * b.sin() }
+    }
+}
+
+/// a direction with the cosine of its angle to `normal` equal to `cos`, at a random angle around it.
+fn around<R: Rng + ?Sized>(rng: &mut R, normal: Vec3, cos: f32) -> Vec3 {
+    let (t, b) = normal.any_orthonormal_basis();
+    let sin = (1.0 - cos * cos).max(0.0).sqrt();
+    let angle = rng.random::<f32>() * TAU;
+    t * (sin * angle.cos()) + b * (sin * angle.sin()) + normal * cos
+}
+
+/// points on the edge of a circle with a radius of 1.0.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct UnitCircle;
+impl Distribution<Vec2> for UnitCircle {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec2 {
+        let angle = rng.random::<f32>() * TAU;
+        vec2(angle.cos(), angle.sin())
+    }
+}
+/// points inside a circle with a radius of 1.0.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct UnitDisk;
+impl Distribution<Vec2> for UnitDisk {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec2 {
+        // the area inside a radius grows with its square, so the square root keeps the density even
+        UnitCircle.sample(rng) * rng.random::<f32>().sqrt()
+    }
+}
+/// points on the surface of a sphere with a radius of 1.0, which are also random directions.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct UnitSphere;
+impl Distribution<Vec3> for UnitSphere {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec3 {
+        // slices of a sphere with the same thickness have the same area, so the height is uniform
+        let cos = rng.random::<f32>() * 2.0 - 1.0;
+        around(rng, Vec3::Z, cos)
+    }
+}
+/// points inside a sphere with a radius of 1.0.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct UnitBall;
+impl Distribution<Vec3> for UnitBall {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec3 { UnitSphere.sample(rng) * rng.random::<f32>().cbrt() }
+}
+/// directions on the half of a sphere that `normal` points to.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Hemisphere {
+    /// must be normalised.
+    pub normal: Vec3
+}
+impl Hemisphere {
+    /// normalises `normal`.
+    pub fn new(normal: Vec3) -> Self { Self { normal: normal.normalise() } }
+}
+impl Distribution<Vec3> for Hemisphere {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec3 {
+        let cos = rng.random();
+        around(rng, self.normal, cos)
+    }
+}
+/// directions on the half of a sphere that `normal` points to, with the density proportional to the
+/// cosine of the angle to `normal`. this is the importance sampling of diffuse lighting.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CosineHemisphere {
+    /// must be normalised.
+    pub normal: Vec3
+}
+impl CosineHemisphere {
+    /// normalises `normal`.
+    pub fn new(normal: Vec3) -> Self { Self { normal: normal.normalise() } }
+}
+impl Distribution<Vec3> for CosineHemisphere {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec3 {
+        // a point on a disk projected up onto the hemisphere
+        let cos = rng.random::<f32>().sqrt();
+        around(rng, self.normal, cos)
+    }
+}
+/// directions within `angle` radians of `direction`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Cone {
+    /// must be normalised.
+    pub direction: Vec3,
+    /// the angle from `direction` to the edge of the cone in radians, from 0 to pi.
+    pub angle: f32
+}
+impl Cone {
+    /// normalises `direction`.
+    pub fn new(direction: Vec3, angle: f32) -> Self { Self { direction: direction.normalise(), angle } }
+}
+impl Distribution<Vec3> for Cone {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec3 {
+        let cos = 1.0 - rng.random::<f32>() * (1.0 - self.angle.cos());
+        around(rng, self.direction, cos)
+    }
+}
+
+/// barycentric weights of a random point on a triangle.
+fn barycentric<R: Rng + ?Sized>(rng: &mut R) -> Vec3 {
+    let (u, v) = (rng.random::<f32>().sqrt(), rng.random::<f32>());
+    vec3(1.0 - u, u * (1.0 - v), u * v)
+}
+/// points on the surface of a triangle.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Triangle {
+    pub a: Vec3,
+    pub b: Vec3,
+    pub c: Vec3
+}
+impl Triangle {
+    pub const fn new(a: Vec3, b: Vec3, c: Vec3) -> Self { Self { a, b, c } }
+    pub fn area(&self) -> f32 { (self.b - self.a).cross(self.c - self.a).length() / 2.0 }
+    /// the weights of `a`, `b` and `c` for a random point, which can be used to interpolate other
+    /// vertex data like normals and uvs.
+    pub fn sample_barycentric<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec3 { barycentric(rng) }
+    /// the point with the weights `w` of `a`, `b` and `c`.
+    pub fn interpolate(&self, w: Vec3) -> Vec3 { self.a * w.x + self.b * w.y + self.c * w.z }
+}
+impl Distribution<Vec3> for Triangle {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec3 { self.interpolate(barycentric(rng)) }
+}
+/// points on the surface of a triangle mesh, with bigger triangles picked more often.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MeshSurface {
+    triangles: Vec<Triangle>,
+    /// the area of each triangle added to all of the ones before it.
+    areas: Vec<f64>
+}
+impl MeshSurface {
+    /// a mesh made of every 3 `indices` into `vertices`, the same as a glium `TrianglesList`.
+    /// returns `None` if the mesh has no area. panics if an index is outside of `vertices`.
+    pub fn new<V: Copy + Into<Vec3>, I: Copy + Into<u32>>(vertices: &[V], indices: &[I]) -> Option<Self> {
+        let triangles: Vec<_> = indices.chunks_exact(3).map(|t| {
+            let [a, b, c] = [t[0], t[1], t[2]].map(|i| vertices[i.into() as usize].into());
+            Triangle::new(a, b, c)
+        }).collect();
+        let areas: Vec<_> = triangles.iter().scan(0.0, |total, t| {
+            *total += t.area() as f64;
+            Some(*total)
+        }).collect();
+        (areas.last().copied().unwrap_or(0.0) > 0.0).then_some(Self { triangles, areas })
+    }
+    /// the area of the whole mesh.
+    pub fn area(&self) -> f32 { *self.areas.last().unwrap() as f32 }
+    pub fn triangles(&self) -> &[Triangle] { &self.triangles }
+    /// picks a random triangle and point on it. returns the index of the triangle, which is the
+    /// index of its first vertex index divided by 3, and the weights of its vertices.
+    pub fn sample_barycentric<R: Rng + ?Sized>(&self, rng: &mut R) -> (usize, Vec3) {
+        let t = rng.random::<f64>() * self.areas.last().unwrap();
+        // triangles without area are skipped, since their end is the same as the one before
+        let triangle = self.areas.partition_point(|&a| a <= t).min(self.areas.len() - 1);
+        (triangle, barycentric(rng))
+    }
+}
+impl Distribution<Vec3> for MeshSurface {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec3 {
+        let (triangle, weights) = self.sample_barycentric(rng);
+        self.triangles[triangle].interpolate(weights)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{SeedableRng, rngs::StdRng};
+    use crate::matrices::Mat3;
+    use super::*;
+    const SAMPLES: usize = 20000;
+    fn samples<T>(distribution: impl Distribution<T>) -> Vec<T> {
+        StdRng::seed_from_u64(7).sample_iter(distribution).take(SAMPLES).collect()
+    }
+    /// the fraction of `samples` where `f` is true.
+    fn fraction<T>(samples: &[T], f: impl Fn(&T) -> bool) -> f32 {
+        samples.iter().filter(|s| f(s)).count() as f32 / samples.len() as f32
+    }
+    fn mean(samples: &[Vec3]) -> Vec3 { samples.iter().sum::<Vec3>() / samples.len() as f32 }
+    /// checks a value is within the range expected from sampling, about 4 standard errors.
+    fn assert_near(value: f32, expected: f32, tolerance: f32) {
+        assert!((value - expected).abs() < tolerance, "expected {expected} but got {value}");
+    }
+    #[test]
+    fn boxes() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let (min, max) = (vec3(-1.0, 2.0, 0.0), vec3(3.0, 4.0, 0.5));
+        let points: Vec<Vec3> = (0..SAMPLES).map(|_| rng.random_range(min..max)).collect();
+        assert!(points.iter().all(|p| p.x >= min.x && p.y >= min.y && p.z >= min.z && p.x < max.x && p.y < max.y && p.z < max.z));
+        let m = mean(&points);
+        assert!(m.distance((min + max) / 2.0) < 0.05, "{m:?}");
+        // the variance of a uniform range is its width squared over 12
+        assert_near(points.iter().map(|p| (p.x - 1.0).powi(2)).sum::<f32>() / SAMPLES as f32, 16.0 / 12.0, 0.05);
+        assert_near(fraction(&points, |p| p.z < 0.125), 0.25, 0.015);
+        let ints: Vec<IVec2> = (0..SAMPLES).map(|_| rng.random_range(ivec2(0, -2)..=ivec2(3, 2))).collect();
+        assert!((0..=3).all(|x| (fraction(&ints, |p| p.x == x) - 0.25).abs() < 0.015));
+        assert!(UniformVec2::new(vec2(0.0, 1.0), vec2(1.0, 1.0)).is_err());
+        let unit: Vec<Vec4> = samples(StandardUniform);
+        assert!(unit.iter().all(|v| v.min_element() >= 0.0 && v.max_element() < 1.0));
+        assert_near(fraction(&samples::<BVec3>(StandardUniform), |b| b.x), 0.5, 0.015);
+    }
+    #[test]
+    fn circles_and_spheres() {
+        let circle = samples(UnitCircle);
+        assert!(circle.iter().all(|p| (p.length() - 1.0).abs() < 1e-5));
+        assert_near(fraction(&circle, |p| p.x > 0.0 && p.y > 0.0), 0.25, 0.015);
+        let disk = samples(UnitDisk);
+        assert!(disk.iter().all(|p| p.length() <= 1.0));
+        // the inner half of the radius has a quarter of the area
+        assert_near(fraction(&disk, |p| p.length() < 0.5), 0.25, 0.015);
+        assert_near(fraction(&disk, |p| p.x > 0.5), 0.19550, 0.015);
+        let sphere = samples(UnitSphere);
+        assert!(sphere.iter().all(|p| (p.length() - 1.0).abs() < 1e-5));
+        assert!(mean(&sphere).length() < 0.03);
+        // every slice of a sphere with the same thickness has the same area
+        for axis in [Vec3::X, Vec3::Y, Vec3::Z] {
+            assert_near(fraction(&sphere, |p| p.dot(axis) > 0.5), 0.25, 0.015);
+        }
+        let ball = samples(UnitBall);
+        assert!(ball.iter().all(|p| p.length() <= 1.0));
+        assert_near(fraction(&ball, |p| p.length() < 0.5), 0.125, 0.01);
+        assert!(mean(&ball).length() < 0.03);
+    }
+    #[test]
+    fn hemispheres_and_cones() {
+        let normal = vec3(1.0, -2.0, 0.5).normalise();
+        let hemisphere = samples(Hemisphere::new(normal));
+        assert!(hemisphere.iter().all(|p| p.dot(normal) >= 0.0 && (p.length() - 1.0).abs() < 1e-5));
+        // the height above the base is uniform, so its mean is half
+        assert_near(hemisphere.iter().map(|p| p.dot(normal)).sum::<f32>() / SAMPLES as f32, 0.5, 0.01);
+        let cosine = samples(CosineHemisphere::new(-Vec3::Z));
+        assert!(cosine.iter().all(|p| p.z <= 0.0 && (p.length() - 1.0).abs() < 1e-5));
+        // the cosine is distributed with a density of 2 cos, so its mean is 2/3 and a quarter are below 0.5
+        assert_near(cosine.iter().map(|p| -p.z).sum::<f32>() / SAMPLES as f32, 2.0 / 3.0, 0.01);
+        assert_near(fraction(&cosine, |p| -p.z < 0.5), 0.25, 0.015);
+        assert!(mean(&cosine).truncate().length() < 0.02);
+        let cone = Cone::new(vec3(0.0, 3.0, 0.0), 0.4);
+        let directions = samples(cone);
+        assert!(directions.iter().all(|p| p.angle_between(Vec3::Y) <= 0.4 + 1e-3));
+        assert_near(directions.iter().map(|p| p.y).sum::<f32>() / SAMPLES as f32, (1.0 + 0.4f32.cos()) / 2.0, 0.002);
+        assert_near(fraction(&directions, |p| p.x > 0.0), 0.5, 0.015);
+        assert!(samples(Cone::new(Vec3::X, std::f32::consts::PI)).iter().all(|p| (p.length() - 1.0).abs() < 1e-5));
+    }
+    #[test]
+    fn rotations() {
+        let rotations: Vec<Quat> = samples(StandardUniform);
+        assert!(rotations.iter().all(|q| (q.r * q.r + q.i * q.i + q.j * q.j + q.k * q.k - 1.0).abs() < 1e-5));
+        // a uniform rotation sends any direction to a uniform direction
+        let rotated: Vec<_> = rotations.iter().map(|&q| Mat3::from(q) * vec3(0.6, 0.0, 0.8)).collect();
+        assert!(mean(&rotated).length() < 0.03);
+        assert_near(fraction(&rotated, |p| p.y > 0.5), 0.25, 0.015);
+        // and each component squared has a mean of 1/4
+        assert_near(rotations.iter().map(|q| q.r * q.r).sum::<f32>() / SAMPLES as f32, 0.25, 0.01);
+        assert_near(rotations.iter().map(|q| q.k * q.k).sum::<f32>() / SAMPLES as f32, 0.25, 0.01);
+        let dq: Vec<DQuat> = samples(StandardUniform);
+        assert!(dq.iter().all(|q| (q.r * q.r + q.i * q.i + q.j * q.j + q.k * q.k - 1.0).abs() < 1e-12));
+    }
+    #[test]
+    fn triangles_and_meshes() {
+        let triangle = Triangle::new(Vec3::ZERO, vec3(2.0, 0.0, 0.0), vec3(0.0, 2.0, 0.0));
+        let points = samples(triangle);
+        assert!(points.iter().all(|p| p.x >= 0.0 && p.y >= 0.0 && p.x + p.y <= 2.0 + 1e-5 && p.z == 0.0));
+        assert!(mean(&points).distance(vec3(2.0, 2.0, 0.0) / 3.0) < 0.02);
+        // the triangle between the midpoints of the sides has a quarter of the area
+        assert_near(fraction(&points, |p| p.x + p.y >= 1.0 && p.x <= 1.0 && p.y <= 1.0), 0.25, 0.015);
+        // a square of area 1 and a square of area 3, with a triangle that has no area
+        let vertices = [
+            vec3(0.0, 0.0, 0.0), vec3(1.0, 0.0, 0.0), vec3(1.0, 1.0, 0.0), vec3(0.0, 1.0, 0.0),
+            vec3(0.0, 0.0, 5.0), vec3(3.0, 0.0, 5.0), vec3(3.0, 1.0, 5.0), vec3(0.0, 1.0, 5.0),
+        ];
+        let indices: [u16; 15] = [0, 1, 2, 0, 2, 3, 0, 0, 0, 4, 5, 6, 4, 6, 7];
+        let mesh = MeshSurface::new(&vertices, &indices).unwrap();
+        assert!(mesh.area() == 4.0);
+        let mut rng = StdRng::seed_from_u64(3);
+        let picked: Vec<_> = (0..SAMPLES).map(|_| mesh.sample_barycentric(&mut rng).0).collect();
+        assert!(!picked.contains(&2));
+        assert_near(fraction(&picked, |&t| t < 2), 0.25, 0.015);
+        let points = samples(&mesh);
+        assert_near(fraction(&points, |p| p.z > 2.5), 0.75, 0.015);
+        assert_near(fraction(&points, |p| p.x > 1.5), 0.375, 0.015);
+        assert!(MeshSurface::new(&vertices, &[0u32, 1, 1]).is_none());
+        assert!(MeshSurface::new(&crate::teapot::VERTICES, &crate::teapot::INDICES).is_some());
+    }
+}
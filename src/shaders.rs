@@ -36,3 +36,463 @@ void main() {
     gl_Position = perspective * camera * model * vec4(position, 1.0);
     v_position = gl_Position.xyz / gl_Position.w;
 }";
+
+/// glsl function that decodes an `OctahedralNormal`. declare the attribute as `in vec2 normal;`
+/// and call `decode_octahedral(normal)` to get the normalised vector.
+pub const OCTAHEDRAL_DECODE: &str =
+"vec3 decode_octahedral(vec2 e) {
+    vec3 n = vec3(e, 1.0 - abs(e.x) - abs(e.y));
+    float t = max(-n.z, 0.0);
+    n.x += n.x >= 0.0 ? -t : t;
+    n.y += n.y >= 0.0 ? -t : t;
+    return normalize(n);
+}";
+
+/// glsl function that decodes an `Rgb9e5` given as a `uint`. textures can use the `GL_RGB9_E5`
+/// format directly instead.
+pub const RGB9E5_DECODE: &str =
+"vec3 decode_rgb9e5(uint p) {
+    uvec3 mantissa = uvec3(p, p >> 9u, p >> 18u) & 511u;
+    return vec3(mantissa) * exp2(float(int(p >> 27u) - 24));
+}";
+
+/// glsl function that decodes an `Rgbe` given as a `uvec4`, without `normalize(true)`.
+pub const RGBE_DECODE: &str =
+"vec3 decode_rgbe(uvec4 p) {
+    if (p.a == 0u) return vec3(0.0);
+    return (vec3(p.rgb) + 0.5) * exp2(float(int(p.a) - 136));
+}";
+
+/// the public glsl functions for one kind of noise, for `vec2`, `vec3` and `vec4`.
+macro_rules! glsl_noise {
+    ($name:literal, $kind:literal) => {
+        glsl_noise!($name, $kind, "vec2", "2"; "vec3", "3"; "vec4", "4";)
+    };
+    ($name:literal, $kind:literal, $($vec:literal, $n:literal;)*) => { concat!($(
+"
+float ", $name, "(", $vec, " p) {
+    vec4 g;
+    return noise_kind(", $kind, ", noise_pad(p, 0.0), vec4(1.0), ", $n, ", false, g);
+}
+float ", $name, "_gradient(", $vec, " p, out ", $vec, " gradient) {
+    vec4 g;
+    float n = noise_kind(", $kind, ", noise_pad(p, 0.0), vec4(1.0), ", $n, ", false, g);
+    gradient = ", $vec, "(g);
+    return n;
+}"),*) };
+}
+/// the public glsl functions for noise that can be tiled.
+macro_rules! glsl_tiled_noise {
+    ($name:literal, $kind:literal) => {
+        glsl_tiled_noise!($name, $kind, "vec2", "2"; "vec3", "3"; "vec4", "4";)
+    };
+    ($name:literal, $kind:literal, $($vec:literal, $n:literal;)*) => { concat!($(
+"
+float tiled_", $name, "(", $vec, " p, ", $vec, " period) {
+    vec4 g;
+    return noise_kind(", $kind, ", noise_pad(p, 0.0), noise_pad(period, 1.0), ", $n, ", true, g);
+}
+float tiled_", $name, "_gradient(", $vec, " p, ", $vec, " period, out ", $vec, " gradient) {
+    vec4 g;
+    float n = noise_kind(", $kind, ", noise_pad(p, 0.0), noise_pad(period, 1.0), ", $n, ", true, g);
+    gradient = ", $vec, "(g);
+    return n;
+}"),*) };
+}
+/// the public glsl octave functions for one kind of noise.
+macro_rules! glsl_octaves {
+    ($name:literal, $kind:literal) => {
+        glsl_octaves!($name, $kind, "vec2", "2"; "vec3", "3"; "vec4", "4";)
+    };
+    ($name:literal, $kind:literal, $($vec:literal, $n:literal;)*) => { concat!($(
+"
+float ", $name, "_fbm(", $vec, " p, int octaves, float lacunarity, float gain) {
+    return noise_octaves(", $kind, ", 0, noise_pad(p, 0.0), ", $n, ", octaves, lacunarity, gain);
+}
+float ", $name, "_ridged(", $vec, " p, int octaves, float lacunarity, float gain) {
+    return noise_octaves(", $kind, ", 1, noise_pad(p, 0.0), ", $n, ", octaves, lacunarity, gain);
+}
+float ", $name, "_turbulence(", $vec, " p, int octaves, float lacunarity, float gain) {
+    return noise_octaves(", $kind, ", 2, noise_pad(p, 0.0), ", $n, ", octaves, lacunarity, gain);
+}
+float ", $name, "_fbm_gradient(", $vec, " p, int octaves, float lacunarity, float gain, out ", $vec, " gradient) {
+    vec4 g;
+    float n = noise_fbm_gradient(", $kind, ", noise_pad(p, 0.0), ", $n, ", octaves, lacunarity, gain, g);
+    gradient = ", $vec, "(g);
+    return n;
+}"),*) };
+}
+
+/// glsl versions of the functions in `noise`, with the same names and hash, so they give the same
+/// noise as the cpu. needs glsl 1.30 or later. the `_gradient` functions return the derivative
+/// through an `out` parameter, e.g `float n = simplex_noise_gradient(p, gradient);`. glsl can't take
+/// functions as arguments, so octaves are `perlin_fbm(p, octaves, lacunarity, gain)`,
+/// `simplex_ridged`, `value_turbulence`, `worley_fbm_gradient` etc. instead of `fbm(p, ..)`, and
+/// 0 octaves is treated as 1 like in rust.
+/// functions starting with `noise_` are used internally.
+pub const NOISE: &str = concat!(
+"uint noise_hash(uint x) {
+    x ^= x >> 16u;
+    x *= 0x7feb352du;
+    x ^= x >> 15u;
+    x *= 0x846ca68bu;
+    x ^= x >> 16u;
+    return x;
+}
+uint noise_hash_cell(vec4 cell, int n) {
+    uint h = 0u;
+    for (int j = n - 1; j >= 0; j--) h = noise_hash(uint(int(cell[j])) ^ h);
+    return h;
+}
+float noise_unit(uint h) { return float(h >> 8u) * (1.0 / 16777216.0); }
+float noise_sign(uint h, uint bit) { return (h & bit) == 0u ? 1.0 : -1.0; }
+vec4 noise_gradient(uint h, int n) {
+    vec4 g = vec4(0.0);
+    if (n == 2) {
+        uint m = h & 7u;
+        int u = m < 4u ? 0 : 1;
+        g[u] = noise_sign(h, 1u);
+        g[1 - u] = noise_sign(h, 2u) * 2.0;
+    } else if (n == 3) {
+        uint m = h & 15u;
+        int u = m < 8u ? 0 : 1;
+        int v = m < 4u ? 1 : (m == 12u || m == 14u) ? 0 : 2;
+        g[u] = noise_sign(h, 1u);
+        g[v] = noise_sign(h, 2u);
+    } else {
+        uint m = h & 31u;
+        int u = m < 24u ? 0 : 1;
+        int v = m < 16u ? 1 : 2;
+        int w = m < 8u ? 2 : 3;
+        g[u] = noise_sign(h, 1u);
+        g[v] = noise_sign(h, 2u);
+        g[w] = noise_sign(h, 4u);
+    }
+    return g;
+}
+vec4 noise_wrap(vec4 cell, vec4 period, bool tiled) {
+    return tiled ? cell - period * floor(cell / period) : cell;
+}
+vec4 noise_pad(vec2 p, float x) { return vec4(p, x, x); }
+vec4 noise_pad(vec3 p, float x) { return vec4(p, x); }
+vec4 noise_pad(vec4 p, float x) { return p; }
+float noise_lattice(vec4 p, vec4 period, int n, bool tiled, bool perlin, out vec4 gradient) {
+    vec4 i = floor(p);
+    vec4 f = p - i;
+    vec4 u = f * f * f * (f * (f * 6.0 - 15.0) + 10.0);
+    vec4 du = 30.0 * f * f * (f * (f - 2.0) + 1.0);
+    float value = 0.0;
+    gradient = vec4(0.0);
+    for (int c = 0; c < 1 << n; c++) {
+        vec4 o = vec4(c & 1, (c >> 1) & 1, (c >> 2) & 1, (c >> 3) & 1);
+        uint h = noise_hash_cell(noise_wrap(i + o, period, tiled), n);
+        vec4 g = perlin ? noise_gradient(h, n) : vec4(0.0);
+        float corner = perlin ? dot(g, f - o) : noise_unit(h) * 2.0 - 1.0;
+        bvec4 upper = equal(o, vec4(1.0));
+        vec4 w = mix(1.0 - u, u, upper);
+        vec4 dw = mix(-du, du, upper);
+        float weight = w.x * w.y * w.z * w.w;
+        value += weight * corner;
+        for (int j = 0; j < n; j++) {
+            vec4 partial = w;
+            partial[j] = dw[j];
+            gradient[j] += weight * g[j] + corner * partial.x * partial.y * partial.z * partial.w;
+        }
+    }
+    // scales perlin noise to -1.0 to 1.0, the same as `perlin_scale` in rust
+    float scale = !perlin ? 1.0 : n == 2 ? 0.6324555 : n == 3 ? 0.8164966 : 0.5773503;
+    gradient *= scale;
+    return value * scale;
+}
+float noise_worley(vec4 p, vec4 period, int n, bool tiled, out vec4 gradient) {
+    vec4 i = floor(p);
+    vec4 f = p - i;
+    float closest = 1e30;
+    vec4 direction = vec4(0.0);
+    int count = n == 2 ? 9 : n == 3 ? 27 : 81;
+    for (int c = 0; c < count; c++) {
+        vec4 o = vec4(0.0);
+        int k = c;
+        for (int j = 0; j < n; j++) {
+            o[j] = float(k % 3) - 1.0;
+            k /= 3;
+        }
+        uint h = noise_hash_cell(noise_wrap(i + o, period, tiled), n);
+        vec4 d = vec4(0.0);
+        for (int j = 0; j < n; j++) {
+            d[j] = o[j] + noise_unit(h) - f[j];
+            h = noise_hash(h);
+        }
+        float distance = dot(d, d);
+        if (distance < closest) {
+            closest = distance;
+            direction = d;
+        }
+    }
+    float distance = sqrt(closest);
+    gradient = distance == 0.0 ? vec4(0.0) : -direction / distance;
+    return distance;
+}
+float noise_simplex(vec4 p, int n, out vec4 gradient) {
+    float skew = n == 2 ? 0.3660254 : n == 3 ? 1.0 / 3.0 : 0.309017;
+    float unskew = n == 2 ? 0.21132487 : n == 3 ? 1.0 / 6.0 : 0.1381966;
+    float scale = n == 2 ? 45.0 : n == 3 ? 76.5 : 62.0;
+    // only the first n components are used
+    vec4 used = vec4(lessThan(ivec4(0, 1, 2, 3), ivec4(n)));
+    float s = (p.x + p.y + p.z + p.w) * skew;
+    vec4 i = floor(p + s) * used;
+    float u = (i.x + i.y + i.z + i.w) * unskew;
+    vec4 x0 = (p - i + u) * used;
+    ivec4 rank = ivec4(0);
+    for (int j = 0; j < n; j++) {
+        for (int k = 0; k < n; k++) {
+            if (x0[j] > x0[k] || (x0[j] == x0[k] && j > k)) rank[j]++;
+        }
+    }
+    float value = 0.0;
+    gradient = vec4(0.0);
+    for (int c = 0; c <= n; c++) {
+        vec4 o = vec4(greaterThanEqual(rank + c, ivec4(n))) * used;
+        vec4 d = (x0 - o + float(c) * unskew) * used;
+        float t = 0.5 - dot(d, d);
+        if (t > 0.0) {
+            vec4 g = noise_gradient(noise_hash_cell(i + o, n), n);
+            float gd = dot(g, d);
+            float t2 = t * t;
+            float t4 = t * t * t * t;
+            value += t4 * gd;
+            gradient += t4 * g - 8.0 * t2 * t * gd * d;
+        }
+    }
+    gradient *= scale;
+    return value * scale;
+}
+// kind is 0 for perlin, 1 for simplex, 2 for value and 3 for worley
+float noise_kind(int kind, vec4 p, vec4 period, int n, bool tiled, out vec4 gradient) {
+    if (kind == 1) return noise_simplex(p, n, gradient);
+    if (kind == 3) return noise_worley(p, period, n, tiled, gradient);
+    return noise_lattice(p, period, n, tiled, kind == 0, gradient);
+}
+// mode is 0 for fbm, 1 for ridged and 2 for turbulence. 0 octaves is treated as 1 here and in
+// noise_fbm_gradient, the same as in rust
+float noise_octaves(int kind, int mode, vec4 p, int n, int octaves, float lacunarity, float gain) {
+    octaves = max(octaves, 1);
+    float sum = 0.0;
+    float amplitude = 1.0;
+    float total = 0.0;
+    vec4 g;
+    for (int o = 0; o < octaves; o++) {
+        float v = noise_kind(kind, p, vec4(1.0), n, false, g);
+        if (mode == 1) {
+            v = 1.0 - abs(v);
+            v *= v;
+        } else if (mode == 2) {
+            v = abs(v);
+        }
+        sum += amplitude * v;
+        total += amplitude;
+        p *= lacunarity;
+        amplitude *= gain;
+    }
+    return sum / total;
+}
+float noise_fbm_gradient(int kind, vec4 p, int n, int octaves, float lacunarity, float gain, out vec4 gradient) {
+    float sum = noise_kind(kind, p, vec4(1.0), n, false, gradient);
+    float total = 1.0;
+    float amplitude = gain;
+    float frequency = lacunarity;
+    p *= lacunarity;
+    for (int o = 1; o < octaves; o++) {
+        vec4 g;
+        sum += amplitude * noise_kind(kind, p, vec4(1.0), n, false, g);
+        gradient += g * (amplitude * frequency);
+        total += amplitude;
+        p *= lacunarity;
+        amplitude *= gain;
+        frequency *= lacunarity;
+    }
+    gradient *= 1.0 / total;
+    return sum / total;
+}",
+glsl_noise!("perlin_noise", "0"), glsl_tiled_noise!("perlin_noise", "0"),
+glsl_noise!("simplex_noise", "1"),
+glsl_noise!("value_noise", "2"), glsl_tiled_noise!("value_noise", "2"),
+glsl_noise!("worley_noise", "3"), glsl_tiled_noise!("worley_noise", "3"),
+glsl_octaves!("perlin", "0"), glsl_octaves!("simplex", "1"), glsl_octaves!("value", "2"), glsl_octaves!("worley", "3"),
+"\n");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    /// parses and validates a fragment shader made of the snippets and `body` as `main`.
+    fn validate(snippets: &[&str], body: &str) {
+        let source = format!(
+            "#version 450 core\n{}\nlayout(location = 0) out vec4 colour;\nvoid main() {{\n{body}\n}}",
+            snippets.join("\n")
+        );
+        let options = naga::front::glsl::Options::from(naga::ShaderStage::Fragment);
+        let module = naga::front::glsl::Frontend::default().parse(&options, &source)
+            .unwrap_or_else(|e| panic!("{}", e.emit_to_string(&source)));
+        naga::valid::Validator::new(naga::valid::ValidationFlags::all(), naga::valid::Capabilities::all())
+            .validate(&module).unwrap_or_else(|e| panic!("{}", e.emit_to_string(&source)));
+    }
+    #[test]
+    fn snippets_compile() {
+        validate(&[OCTAHEDRAL_DECODE, RGB9E5_DECODE, RGBE_DECODE], "
+            colour = vec4(decode_octahedral(vec2(0.5)) + decode_rgb9e5(7u) + decode_rgbe(uvec4(1u, 2u, 3u, 130u)), 1.0);
+        ");
+        validate(&[NOISE], "
+            vec2 a; vec3 b; vec4 c;
+            float n = perlin_noise(vec2(0.5)) + simplex_noise(vec3(0.5)) + value_noise(vec4(0.5)) + worley_noise(vec2(0.5));
+            n += perlin_noise_gradient(vec3(0.5), b) + simplex_noise_gradient(vec4(0.5), c);
+            n += tiled_value_noise_gradient(vec2(0.5), vec2(4.0), a) + tiled_worley_noise(vec3(0.5), vec3(2.0));
+            n += perlin_fbm(vec2(0.5), 4, 2.0, 0.5) + simplex_ridged(vec3(0.5), 4, 2.0, 0.5);
+            n += value_turbulence(vec4(0.5), 4, 2.0, 0.5) + worley_fbm_gradient(vec2(0.5), 4, 2.0, 0.5, a);
+            colour = vec4(n, a, 1.0) + vec4(b, 0.0) + c;
+        ");
+    }
+    /// a glium context with no window, from egl's surfaceless platform.
+    fn headless_context() -> std::rc::Rc<glium::backend::Context> {
+        use khronos_egl as egl;
+        struct Backend(egl::DynamicInstance<egl::EGL1_5>);
+        unsafe impl glium::backend::Backend for Backend {
+            fn swap_buffers(&self) -> Result<(), glium::SwapBuffersError> { Ok(()) }
+            unsafe fn get_proc_address(&self, symbol: &str) -> *const std::os::raw::c_void {
+                self.0.get_proc_address(symbol).map_or(std::ptr::null(), |f| f as _)
+            }
+            fn get_framebuffer_dimensions(&self) -> (u32, u32) { (1, 1) }
+            fn resize(&self, _: (u32, u32)) {}
+            fn is_current(&self) -> bool { true }
+            unsafe fn make_current(&self) {}
+        }
+        const PLATFORM_SURFACELESS_MESA: egl::Enum = 0x31DD;
+        let egl = unsafe { egl::DynamicInstance::<egl::EGL1_5>::load_required() }.expect("libEGL not found");
+        let display = unsafe {
+            egl.get_platform_display(PLATFORM_SURFACELESS_MESA, std::ptr::null_mut(), &[egl::ATTRIB_NONE])
+        }.unwrap();
+        egl.initialize(display).unwrap();
+        egl.bind_api(egl::OPENGL_API).unwrap();
+        let config = egl.choose_first_config(display, &[
+            egl::RENDERABLE_TYPE, egl::OPENGL_BIT, egl::SURFACE_TYPE, egl::PBUFFER_BIT, egl::NONE
+        ]).unwrap().expect("no opengl egl config");
+        let context = egl.create_context(display, config, None, &[
+            egl::CONTEXT_MAJOR_VERSION, 3, egl::CONTEXT_MINOR_VERSION, 3,
+            egl::CONTEXT_OPENGL_PROFILE_MASK, egl::CONTEXT_OPENGL_CORE_PROFILE_BIT, egl::NONE
+        ]).unwrap();
+        egl.make_current(display, None, None, Some(context)).unwrap();
+        unsafe { glium::backend::Context::new(Backend(egl), false, Default::default()) }.unwrap()
+    }
+    /// needs egl and opengl 3.3, e.g. `LIBGL_ALWAYS_SOFTWARE=1 cargo test -- --ignored`. each column
+    /// is one case, which writes up to 4 results, and each row is a point, including negative cells.
+    #[test]
+    #[ignore]
+    fn noise_matches_gpu() {
+        use crate::{noise::*, vectors::*};
+        use glium::{Surface, texture::*};
+        #[derive(Clone, Copy)]
+        struct Vertex { position: [f32; 2] }
+        glium::implement_vertex!(Vertex, position);
+        /// the point for row `y`, the same in glsl.
+        fn point(y: f32) -> Vec4 { vec4(y * 0.37 - 3.1, 1.7 - y * 0.53, y * 0.29 - 2.3, 1.3 - y * 0.41) }
+        let g2 = |(n, g): (f32, Vec2)| [n, g.x, g.y, 0.0];
+        let g3 = |(n, g): (f32, Vec3)| [n, g.x, g.y, g.z];
+        let (periods2, periods3, periods4) = (vec2(3.0, 5.0), vec3(2.0, 3.0, 4.0), vec4(2.0, 3.0, 4.0, 5.0));
+        /// the glsl for a pixel and the same maths in rust, given the point as a vec2, vec3 and vec4.
+        type Case<'a> = (&'a str, &'a dyn Fn(Vec2, Vec3, Vec4) -> [f32; 4]);
+        let cases: [Case; 18] = [
+            ("vec4(perlin_noise(p.xy), perlin_noise(p.xyz), perlin_noise(p), simplex_noise(p.xy))",
+                &|a, b, c| [perlin_noise(a), perlin_noise(b), perlin_noise(c), simplex_noise(a)]),
+            ("vec4(simplex_noise(p.xyz), simplex_noise(p), value_noise(p.xy), value_noise(p.xyz))",
+                &|a, b, c| [simplex_noise(b), simplex_noise(c), value_noise(a), value_noise(b)]),
+            ("vec4(value_noise(p), worley_noise(p.xy), worley_noise(p.xyz), worley_noise(p))",
+                &|a, b, c| [value_noise(c), worley_noise(a), worley_noise(b), worley_noise(c)]),
+            ("vec4(perlin_noise_gradient(p.xyz, b), b)", &|_, b, _| g3(perlin_noise_gradient(b))),
+            ("vec4(perlin_noise_gradient(p, c), c.yzw)", &|_, _, c| {
+                let (n, g) = perlin_noise_gradient(c);
+                [n, g.y, g.z, g.w]
+            }),
+            ("vec4(simplex_noise_gradient(p.xy, a), a, 0.0)", &|a, _, _| g2(simplex_noise_gradient(a))),
+            ("vec4(simplex_noise_gradient(p.xyz, b), b)", &|_, b, _| g3(simplex_noise_gradient(b))),
+            ("vec4(simplex_noise_gradient(p, c), c.xyz)", &|_, _, c| {
+                let (n, g) = simplex_noise_gradient(c);
+                [n, g.x, g.y, g.z]
+            }),
+            ("vec4(value_noise_gradient(p.xy, a), a, 0.0)", &|a, _, _| g2(value_noise_gradient(a))),
+            ("vec4(worley_noise_gradient(p.xyz, b), b)", &|_, b, _| g3(worley_noise_gradient(b))),
+            ("vec4(tiled_perlin_noise_gradient(p.xyz, vec3(2.0, 3.0, 4.0), b), b)",
+                &move |_, b, _| g3(tiled_perlin_noise_gradient(b, periods3))),
+            ("vec4(tiled_value_noise_gradient(p.xy, vec2(3.0, 5.0), a), a, tiled_perlin_noise(p.xy, vec2(3.0, 5.0)))",
+                &move |a, _, _| {
+                    let (n, g) = tiled_value_noise_gradient(a, periods2);
+                    [n, g.x, g.y, tiled_perlin_noise(a, periods2)]
+                }),
+            ("vec4(tiled_worley_noise_gradient(p.xyz, vec3(2.0, 3.0, 4.0), b), b)",
+                &move |_, b, _| g3(tiled_worley_noise_gradient(b, periods3))),
+            ("vec4(
+                tiled_perlin_noise(p, vec4(2.0, 3.0, 4.0, 5.0)), tiled_value_noise(p, vec4(2.0, 3.0, 4.0, 5.0)),
+                tiled_worley_noise(p, vec4(2.0, 3.0, 4.0, 5.0)), tiled_value_noise(p.xyz, vec3(2.0, 3.0, 4.0))
+            )", &move |_, b, c| [
+                tiled_perlin_noise(c, periods4), tiled_value_noise(c, periods4),
+                tiled_worley_noise(c, periods4), tiled_value_noise(b, periods3)
+            ]),
+            ("vec4(
+                perlin_fbm(p.xy, 5, 2.0, 0.5), simplex_ridged(p.xyz, 4, 2.1, 0.5),
+                value_turbulence(p, 4, 2.0, 0.6), worley_fbm(p.xy, 3, 2.0, 0.5)
+            )", &|a, b, c| [
+                fbm(a, 5, 2.0, 0.5, perlin_noise), ridged(b, 4, 2.1, 0.5, simplex_noise),
+                turbulence(c, 4, 2.0, 0.6, value_noise), fbm(a, 3, 2.0, 0.5, worley_noise)
+            ]),
+            ("vec4(
+                perlin_ridged(p, 3, 2.0, 0.5), perlin_turbulence(p.xy, 4, 2.0, 0.5),
+                worley_ridged(p.xyz, 3, 2.0, 0.5), value_fbm(p.xy, 0, 2.0, 0.5)
+            )", &|a, b, c| [
+                ridged(c, 3, 2.0, 0.5, perlin_noise), turbulence(a, 4, 2.0, 0.5, perlin_noise),
+                ridged(b, 3, 2.0, 0.5, worley_noise), fbm(a, 0, 2.0, 0.5, value_noise)
+            ]),
+            ("vec4(simplex_fbm_gradient(p.xyz, 5, 2.0, 0.5, b), b)",
+                &|_, b, _| g3(fbm_gradient(b, 5, 2.0, 0.5, simplex_noise_gradient))),
+            ("vec4(perlin_fbm_gradient(p.xy, 4, 2.0, 0.5, a), a, worley_fbm_gradient(p.xy, 3, 2.0, 0.5, e))",
+                &|a, _, _| {
+                    let (n, g) = fbm_gradient(a, 4, 2.0, 0.5, perlin_noise_gradient);
+                    [n, g.x, g.y, fbm_gradient(a, 3, 2.0, 0.5, worley_noise_gradient).0]
+                }),
+        ];
+        const ROWS: usize = 16;
+
+        let context = headless_context();
+        let vertex = "#version 330\nin vec2 position;\nvoid main() { gl_Position = vec4(position, 0.0, 1.0); }";
+        let body: String = cases.iter().enumerate()
+            .map(|(i, (glsl, _))| format!("if (column == {i}) colour = {glsl};\n"))
+            .collect();
+        let fragment = format!("#version 330\n{NOISE}\nout vec4 colour;\nvoid main() {{
+            int column = int(gl_FragCoord.x);
+            float y = floor(gl_FragCoord.y);
+            vec4 p = vec4(y * 0.37 - 3.1, 1.7 - y * 0.53, y * 0.29 - 2.3, 1.3 - y * 0.41);
+            vec2 a, e; vec3 b; vec4 c;
+            {body}
+        }}");
+        let program = glium::Program::from_source(&context, vertex, &fragment, None)
+            .unwrap_or_else(|e| panic!("{e}"));
+        let triangle = [[-1.0, -1.0], [3.0, -1.0], [-1.0, 3.0]].map(|position| Vertex { position });
+        let vertices = glium::VertexBuffer::new(&context, &triangle).unwrap();
+        let (width, height) = (cases.len() as u32, ROWS as u32);
+        let texture = Texture2d::empty_with_format(
+            &context, UncompressedFloatFormat::F32F32F32F32, MipmapsOption::NoMipmap, width, height
+        ).unwrap();
+        glium::framebuffer::SimpleFrameBuffer::new(&context, &texture).unwrap().draw(
+            &vertices, glium::index::NoIndices(glium::index::PrimitiveType::TrianglesList),
+            &program, &glium::uniforms::EmptyUniforms, &Default::default()
+        ).unwrap();
+        let pixels: Vec<Vec<(f32, f32, f32, f32)>> = texture.main_level().first_layer().into_image(None).unwrap()
+            .raw_read(&glium::Rect { left: 0, bottom: 0, width, height });
+        for (y, row) in pixels.iter().enumerate() {
+            let p = point(y as f32);
+            for ((glsl, cpu), &(r, g, b, a)) in cases.iter().zip(row) {
+                let cpu = cpu(p.truncate().truncate(), p.truncate(), p);
+                for (cpu, gpu) in cpu.into_iter().zip([r, g, b, a]) {
+                    assert!((cpu - gpu).abs() <= 1e-4 * cpu.abs().max(1.0), "{glsl} at {p:?}: {cpu} != {gpu}");
+                }
+            }
+        }
+    }
+}
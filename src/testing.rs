@@ -0,0 +1,43 @@
+//! helpers shared by the tests of different modules. not every feature uses all of them.
+#![allow(dead_code)]
+use crate::prelude::*;
+
+/// how many random values each property test is checked with.
+pub const CASES: usize = 256;
+/// a xorshift generator for tests that cover lots of values, seeded the same every time so
+/// failures can be reproduced.
+pub struct Random(u64);
+impl Random {
+    pub fn new() -> Self { Self(0x9e37_79b9_7f4a_7c15) }
+    pub fn u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+    /// a float from -10 to 10.
+    pub fn f64(&mut self) -> f64 { (self.u64() >> 11) as f64 / (1u64 << 53) as f64 * 20.0 - 10.0 }
+    pub fn f32(&mut self) -> f32 { self.f64() as f32 }
+    pub fn i32(&mut self) -> i32 { self.u64() as i32 }
+    pub fn bool(&mut self) -> bool { self.u64() & 1 == 1 }
+    pub fn vec3(&mut self) -> Vec3 { vec3(self.f32(), self.f32(), self.f32()) }
+    pub fn vec4(&mut self) -> Vec4 { vec4(self.f32(), self.f32(), self.f32(), self.f32()) }
+    pub fn dvec3(&mut self) -> DVec3 { dvec3(self.f64(), self.f64(), self.f64()) }
+    pub fn dvec4(&mut self) -> DVec4 { dvec4(self.f64(), self.f64(), self.f64(), self.f64()) }
+    pub fn mat3(&mut self) -> Mat3 { Mat3::from_colum_major_array([(); 3].map(|_| self.vec3().into())) }
+    pub fn mat4(&mut self) -> Mat4 { Mat4::from_column_major_array([(); 4].map(|_| self.vec4().into())) }
+    pub fn dmat4(&mut self) -> DMat4 {
+        DMat4::from_column_major_array([(); 4].map(|_| self.dvec4().into()))
+    }
+    /// a rotation.
+    pub fn quat(&mut self) -> Quat { Quat::from_axis_rot(self.f32(), self.vec3().normalise()) }
+    pub fn dquat(&mut self) -> DQuat { DQuat::from_axis_rot(self.f64(), self.dvec3().normalise()) }
+}
+
+/// checks that `value` serializes to `json` and deserializes back to `value`.
+#[cfg(feature = "serde")]
+pub fn serde_round_trip<T>(value: T, json: &str)
+where T: serde::Serialize + serde::de::DeserializeOwned + PartialEq + std::fmt::Debug {
+    assert_eq!(serde_json::to_string(&value).unwrap(), json);
+    assert_eq!(serde_json::from_str::<T>(json).unwrap(), value);
+}
@@ -1,7 +1,10 @@
 use glium::uniforms::{AsUniformValue, UniformValue};
 use super::bvec3::*;
 /// a boolean vector with an x and y component
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable))]
+#[repr(C)]
 pub struct BVec2 { pub x: bool, pub y: bool }
 impl BVec2 {
     /// a vector full of trues
@@ -16,6 +19,16 @@ impl BVec2 {
     pub const fn new(x: bool, y: bool) -> Self { Self { x, y } }
     pub const fn truncate(self) -> bool { self.x }
     pub const fn extend(self, z: bool) -> BVec3 { BVec3::new(self.x, self.y, z) }
+    /// returns true if any component is true
+    pub const fn any(self) -> bool { self.x || self.y }
+    /// returns true if every component is true
+    pub const fn all(self) -> bool { self.x && self.y }
+    /// returns true if no component is true
+    pub const fn none(self) -> bool { !self.any() }
+    /// packs the components into the lowest bits of a `u32`. x is the 1st bit, y is the 2nd and so on
+    pub const fn bitmask(self) -> u32 { self.x as u32 | (self.y as u32) << 1 }
+    /// unpacks the lowest bits of `bitmask` into a vector. the inverse of `bitmask`
+    pub const fn from_bitmask(bitmask: u32) -> Self { Self::new(bitmask & 1 != 0, bitmask & 2 != 0) }
 }
 impl AsUniformValue for BVec2 {
     fn as_uniform_value(&self) -> UniformValue<'_> {
@@ -1,7 +1,10 @@
 use glium::uniforms::{AsUniformValue, UniformValue};
 use super::{bvec2::*, bvec4::*};
 /// a boolean vector with an x, y and z component
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable))]
+#[repr(C)]
 pub struct BVec3 { pub x: bool, pub y: bool, pub z: bool }
 impl BVec3 {
     /// a vector full of trues
@@ -18,6 +21,24 @@ impl BVec3 {
     pub const fn new(x: bool, y: bool, z: bool) -> Self { Self { x, y, z } }
     pub const fn truncate(self) -> BVec2 { BVec2::new(self.x, self.y) }
     pub const fn extend(self, w: bool) -> BVec4 { BVec4::new(self.x, self.y, self.z, w) }
+    /// returns true if any component is true
+    pub const fn any(self) -> bool { self.x || self.y || self.z }
+    /// returns true if every component is true
+    pub const fn all(self) -> bool { self.x && self.y && self.z }
+    /// returns true if no component is true
+    pub const fn none(self) -> bool { !self.any() }
+    /// packs the components into the lowest bits of a `u32`. x is the 1st bit, y is the 2nd and so on
+    /// ```
+    /// use glium_types::vectors::{BVec3, bvec3};
+    /// let mask = bvec3(true, false, true);
+    /// assert!(mask.bitmask() == 0b101);
+    /// assert!(BVec3::from_bitmask(0b101).bitmask() == 0b101);
+    /// ```
+    pub const fn bitmask(self) -> u32 { self.x as u32 | (self.y as u32) << 1 | (self.z as u32) << 2 }
+    /// unpacks the lowest bits of `bitmask` into a vector. the inverse of `bitmask`
+    pub const fn from_bitmask(bitmask: u32) -> Self {
+        Self::new(bitmask & 1 != 0, bitmask & 2 != 0, bitmask & 4 != 0)
+    }
 }
 impl AsUniformValue for BVec3 {
     fn as_uniform_value(&self) -> UniformValue<'_> {
@@ -1,7 +1,10 @@
 use glium::uniforms::{AsUniformValue, UniformValue};
 use super::bvec3::*;
 /// a boolean vector with an x, y, z and w component
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable))]
+#[repr(C)]
 pub struct BVec4 { pub x: bool, pub y: bool, pub z: bool, pub w: bool }
 impl BVec4 {
     /// a vector full of trues
@@ -19,6 +22,20 @@ impl BVec4 {
 
     pub const fn new(x: bool, y: bool, z: bool, w: bool) -> Self { Self { x, y, z, w } }
     pub const fn truncate(self) -> BVec3 { BVec3::new(self.x, self.y, self.z) }
+    /// returns true if any component is true
+    pub const fn any(self) -> bool { self.x || self.y || self.z || self.w }
+    /// returns true if every component is true
+    pub const fn all(self) -> bool { self.x && self.y && self.z && self.w }
+    /// returns true if no component is true
+    pub const fn none(self) -> bool { !self.any() }
+    /// packs the components into the lowest bits of a `u32`. x is the 1st bit, y is the 2nd and so on
+    pub const fn bitmask(self) -> u32 {
+        self.x as u32 | (self.y as u32) << 1 | (self.z as u32) << 2 | (self.w as u32) << 3
+    }
+    /// unpacks the lowest bits of `bitmask` into a vector. the inverse of `bitmask`
+    pub const fn from_bitmask(bitmask: u32) -> Self {
+        Self::new(bitmask & 1 != 0, bitmask & 2 != 0, bitmask & 4 != 0, bitmask & 8 != 0)
+    }
 }
 impl AsUniformValue for BVec4 {
     fn as_uniform_value(&self) -> UniformValue<'_> {
@@ -2,8 +2,11 @@ use derive_cmp_ops::CmpOps;
 use glium::uniforms::AsUniformValue;
 
 use super::{divec3::*, vec2::Vec2, ivec2::IVec2, bvec2::*};
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, CmpOps)]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash, Default, CmpOps)]
 /// a double interger vector made from an x and y coordinate.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[repr(C)]
 pub struct DIVec2 {
     pub x: i64,
     pub y: i64,
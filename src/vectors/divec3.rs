@@ -1,8 +1,11 @@
 use derive_cmp_ops::CmpOps;
 use glium::uniforms::AsUniformValue;
 use super::{vec3::Vec3, divec2::{divec2, DIVec2}, divec4::{divec4, DIVec4}, bvec3::*};
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, CmpOps)]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash, Default, CmpOps)]
 /// a double interger vector made from a x, y and z coordinate.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[repr(C)]
 pub struct DIVec3 {
     pub x: i64,
     pub y: i64,
@@ -1,8 +1,11 @@
 use derive_cmp_ops::CmpOps;
 use glium::uniforms::AsUniformValue;
 use super::{vec4::Vec4, divec3::*, bvec4::*};
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, CmpOps)]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash, Default, CmpOps)]
 /// a double interger vector made from a x, y, z and w coordinate.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[repr(C)]
 pub struct DIVec4 {
     pub x: i64,
     pub y: i64,
@@ -2,10 +2,13 @@ use derive_cmp_ops::*;
 use glium::uniforms::AsUniformValue;
 use super::{vec4::Vec4, uvec4::UVec4, duvec3::*, bvec4::*};
 #[derive(
-    Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, CmpAdd, CmpAddAssign, CmpDiv,
-    CmpDivAssign, CmpMul, CmpMulAssign, CmpRem, CmpRemAssign, CmpSub, CmpSubAssign
+    Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash, Default, CmpAdd, CmpAddAssign,
+    CmpDiv, CmpDivAssign, CmpMul, CmpMulAssign, CmpRem, CmpRemAssign, CmpSub, CmpSubAssign
 )]
 /// an unsigned double interger vector made from a x, y, z and w coordinate.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[repr(C)]
 pub struct DUVec4 {
     pub x: u64,
     pub y: u64,
@@ -26,7 +29,7 @@ impl DUVec4 {
     /// the w axis
     pub const W: Self = duvec4(0, 0, 0, 1);
 
-    pub fn new(x: u64, y: u64, z: u64, w: u64) -> Self {
+    pub const fn new(x: u64, y: u64, z: u64, w: u64) -> Self {
         Self { x, y, z, w }
     }
     /// create a vector where x, y and z equals `value`
@@ -5,6 +5,9 @@ use glium::uniforms::AsUniformValue;
 use super::{ dvec3::{dvec3, DVec3}, vec2::Vec2, bvec2::*};
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd, CmpOps)]
 /// a double vector made from a x and y coordinate
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[repr(C)]
 pub struct DVec2 {
     pub x: f64,
     pub y: f64,
@@ -4,6 +4,9 @@ use crate::matrices::DMat3;
 use super::{vec3::Vec3, dvec2::{dvec2, DVec2}, dvec4::{dvec4, DVec4}, bvec3::*};
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd, CmpOps)]
 ///a double vector made from a x, y and z coordinate.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[repr(C)]
 pub struct DVec3{
     pub x: f64,
     pub y: f64,
@@ -72,6 +75,16 @@ impl DVec3{
         if length == 0.0 { return dvec3(0.0, 0.0, 0.0); }
         self.scale(1.0 / length)
     }
+    ///returns 2 normalised vectors that are perpendicular to each other and to `self`. `self` must be
+    ///normalised.
+    pub fn any_orthonormal_basis(self) -> (DVec3, DVec3) {
+        // from "building an orthonormal basis, revisited" by duff et al.
+        let DVec3 { x, y, z } = self;
+        let sign = 1.0f64.copysign(z);
+        let a = -1.0 / (sign + z);
+        let b = x * y * a;
+        (dvec3(1.0 + sign * x * x * a, sign * b, -sign * x), dvec3(b, sign + y * y * a, -y))
+    }
     ///transforms vector by the matrix
     pub fn transform(self, matrix: DMat3) -> Self{
         let a: DVec3 = matrix.row(0).into();
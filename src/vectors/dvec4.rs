@@ -4,6 +4,9 @@ use crate::matrices::DMat4;
 use super::{vec4::Vec4, dvec3::{dvec3, DVec3}, bvec4::*};
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd, CmpOps)]
 /// a double vector made from a x, y, z and w coordinate.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[repr(C)]
 pub struct DVec4 {
     pub x: f64,
     pub y: f64,
@@ -0,0 +1,245 @@
+//! glsl built in functions for the float vectors. the common functions work on each component
+//! separately and match the glsl function with the same name. the geometric functions treat the
+//! vector as a direction.
+use super::*;
+
+macro_rules! impl_float_functions {
+    ($vec:ident, $float:ident; $($c:ident)*) => {
+        impl $vec {
+            /// the absolute value of each component.
+            pub fn abs(self) -> Self { Self { $($c: self.$c.abs()),* } }
+            /// 1.0 for positive components, -1.0 for negative components and 0.0 for zeros.
+            pub fn sign(self) -> Self {
+                Self { $($c: if self.$c == 0.0 { 0.0 } else { self.$c.signum() }),* }
+            }
+            /// rounds each component down.
+            pub fn floor(self) -> Self { Self { $($c: self.$c.floor()),* } }
+            /// rounds each component up.
+            pub fn ceil(self) -> Self { Self { $($c: self.$c.ceil()),* } }
+            /// rounds each component to the nearest whole number. halves round away from zero.
+            pub fn round(self) -> Self { Self { $($c: self.$c.round()),* } }
+            /// rounds each component towards zero.
+            pub fn trunc(self) -> Self { Self { $($c: self.$c.trunc()),* } }
+            /// the fractional part of each component, equal to `x - floor(x)`.
+            pub fn fract(self) -> Self { Self { $($c: self.$c - self.$c.floor()),* } }
+            /// the smaller of each component.
+            pub fn min(self, other: Self) -> Self { Self { $($c: self.$c.min(other.$c)),* } }
+            /// the larger of each component.
+            pub fn max(self, other: Self) -> Self { Self { $($c: self.$c.max(other.$c)),* } }
+            /// the smaller of each component and `other`.
+            pub fn min_scalar(self, other: $float) -> Self { self.min(Self::splat(other)) }
+            /// the larger of each component and `other`.
+            pub fn max_scalar(self, other: $float) -> Self { self.max(Self::splat(other)) }
+            /// limits each component to be between `min` and `max`.
+            pub fn clamp(self, min: Self, max: Self) -> Self { self.max(min).min(max) }
+            /// limits each component to be between `min` and `max`, e.g glsl `clamp(v, 0.0, 1.0)`.
+            pub fn clamp_scalar(self, min: $float, max: $float) -> Self {
+                self.clamp(Self::splat(min), Self::splat(max))
+            }
+            /// linearly interpolates between `self` and `other`. returns `self` when `t` is 0.0
+            /// and `other` when `t` is 1.0.
+            pub fn mix(self, other: Self, t: $float) -> Self {
+                Self { $($c: self.$c * (1.0 - t) + other.$c * t),* }
+            }
+            /// linearly interpolates each component separately by the matching component of `t`.
+            /// the same as glsl `mix` with a vector `t`.
+            pub fn mix_each(self, other: Self, t: Self) -> Self {
+                Self { $($c: self.$c * (1.0 - t.$c) + other.$c * t.$c),* }
+            }
+            /// 0.0 for components less than `edge` and 1.0 for the rest.
+            pub fn step(self, edge: Self) -> Self {
+                Self { $($c: if self.$c < edge.$c { 0.0 } else { 1.0 }),* }
+            }
+            /// 0.0 for components less than `edge` and 1.0 for the rest, e.g glsl `step(0.5, v)`.
+            pub fn step_scalar(self, edge: $float) -> Self { self.step(Self::splat(edge)) }
+            /// smooth hermite interpolation from 0.0 to 1.0 as each component goes from `edge0`
+            /// to `edge1`.
+            pub fn smoothstep(self, edge0: Self, edge1: Self) -> Self {
+                Self { $($c: {
+                    let t = ((self.$c - edge0.$c) / (edge1.$c - edge0.$c)).clamp(0.0, 1.0);
+                    t * t * (3.0 - 2.0 * t)
+                }),* }
+            }
+            /// smooth hermite interpolation from 0.0 to 1.0 as each component goes from `edge0`
+            /// to `edge1`, e.g glsl `smoothstep(0.0, 1.0, v)`.
+            pub fn smoothstep_scalar(self, edge0: $float, edge1: $float) -> Self {
+                self.smoothstep(Self::splat(edge0), Self::splat(edge1))
+            }
+            /// glsl `mod`, equal to `x - y * floor(x / y)`. unlike `%` the result has the same
+            /// sign as `y`.
+            pub fn modulo(self, y: Self) -> Self {
+                Self { $($c: self.$c - y.$c * (self.$c / y.$c).floor()),* }
+            }
+            /// raises each component to the power of the component in `exp`.
+            pub fn pow(self, exp: Self) -> Self { Self { $($c: self.$c.powf(exp.$c)),* } }
+            /// e to the power of each component.
+            pub fn exp(self) -> Self { Self { $($c: self.$c.exp()),* } }
+            /// 2 to the power of each component.
+            pub fn exp2(self) -> Self { Self { $($c: self.$c.exp2()),* } }
+            /// the natural logarithm of each component.
+            pub fn log(self) -> Self { Self { $($c: self.$c.ln()),* } }
+            /// the base 2 logarithm of each component.
+            pub fn log2(self) -> Self { Self { $($c: self.$c.log2()),* } }
+            /// the square root of each component.
+            pub fn sqrt(self) -> Self { Self { $($c: self.$c.sqrt()),* } }
+            /// one over the square root of each component.
+            pub fn inversesqrt(self) -> Self { Self { $($c: 1.0 / self.$c.sqrt()),* } }
+            /// converts each component from degrees to radians.
+            pub fn radians(self) -> Self { Self { $($c: self.$c.to_radians()),* } }
+            /// converts each component from radians to degrees.
+            pub fn degrees(self) -> Self { Self { $($c: self.$c.to_degrees()),* } }
+            /// the sine of each component in radians.
+            pub fn sin(self) -> Self { Self { $($c: self.$c.sin()),* } }
+            /// the cosine of each component in radians.
+            pub fn cos(self) -> Self { Self { $($c: self.$c.cos()),* } }
+            /// the tangent of each component in radians.
+            pub fn tan(self) -> Self { Self { $($c: self.$c.tan()),* } }
+            /// the arcsine of each component in radians.
+            pub fn asin(self) -> Self { Self { $($c: self.$c.asin()),* } }
+            /// the arccosine of each component in radians.
+            pub fn acos(self) -> Self { Self { $($c: self.$c.acos()),* } }
+            /// the arctangent of each component in radians.
+            pub fn atan(self) -> Self { Self { $($c: self.$c.atan()),* } }
+            /// glsl `atan(y, x)` where `self` is y. the angle of each `(x, y)` pair in radians.
+            pub fn atan2(self, x: Self) -> Self { Self { $($c: self.$c.atan2(x.$c)),* } }
+            /// the hyperbolic sine of each component.
+            pub fn sinh(self) -> Self { Self { $($c: self.$c.sinh()),* } }
+            /// the hyperbolic cosine of each component.
+            pub fn cosh(self) -> Self { Self { $($c: self.$c.cosh()),* } }
+            /// the hyperbolic tangent of each component.
+            pub fn tanh(self) -> Self { Self { $($c: self.$c.tanh()),* } }
+        }
+    };
+}
+impl_float_functions!(Vec2, f32; x y);
+impl_float_functions!(Vec3, f32; x y z);
+impl_float_functions!(Vec4, f32; x y z w);
+impl_float_functions!(DVec2, f64; x y);
+impl_float_functions!(DVec3, f64; x y z);
+impl_float_functions!(DVec4, f64; x y z w);
+
+macro_rules! impl_geometric_functions {
+    ($vec:ident, $float:ident) => {
+        impl $vec {
+            /// reflects `self` off a surface with the normal `normal`. `normal` should be
+            /// normalised. the same as glsl `reflect`.
+            pub fn reflect(self, normal: Self) -> Self {
+                self - normal * (2.0 * normal.dot(self))
+            }
+            /// refracts `self` through a surface with the normal `normal`, where `eta` is the ratio
+            /// of indices of refraction. both vectors should be normalised. returns a zero vector
+            /// on total internal reflection. the same as glsl `refract`.
+            pub fn refract(self, normal: Self, eta: $float) -> Self {
+                let dot = normal.dot(self);
+                let k = 1.0 - eta * eta * (1.0 - dot * dot);
+                if k < 0.0 { return Self::ZERO; }
+                self * eta - normal * (eta * dot + k.sqrt())
+            }
+            /// returns `self` if it points away from `incident` (based on `reference`) and `-self`
+            /// otherwise. the same as glsl `faceforward(self, incident, reference)`.
+            pub fn faceforward(self, incident: Self, reference: Self) -> Self {
+                if reference.dot(incident) < 0.0 { self } else { -self }
+            }
+            /// the part of `self` that is parallel to `other`. returns a zero vector if `other`
+            /// is zero.
+            pub fn project_onto(self, other: Self) -> Self {
+                let length_squared = other.length_squared();
+                if length_squared == 0.0 { return Self::ZERO; }
+                other * (self.dot(other) / length_squared)
+            }
+            /// the part of `self` that is perpendicular to `other`.
+            pub fn reject_from(self, other: Self) -> Self {
+                self - self.project_onto(other)
+            }
+            /// the angle between the 2 vectors in radians, from 0 to pi.
+            pub fn angle_between(self, other: Self) -> $float {
+                let lengths = (self.length_squared() * other.length_squared()).sqrt();
+                if lengths == 0.0 { return 0.0; }
+                (self.dot(other) / lengths).clamp(-1.0, 1.0).acos()
+            }
+            /// spherical linear interpolation. rotates from `self` at 0.0 to `other` at 1.0 at a
+            /// constant speed. the vectors should be normalised. opposite vectors rotate through
+            /// an arbitrary perpendicular direction.
+            pub fn slerp(self, other: Self, t: $float) -> Self {
+                let angle = self.angle_between(other);
+                let sin = angle.sin();
+                if sin.abs() < 1e-6 {
+                    // the vectors are too close to find a rotation, so a straight line is enough
+                    if angle < 1.0 { return self.mix(other, t); }
+                    // they are opposite, so rotate towards the axis `self` is least aligned with
+                    let (axis, _) = self.abs().into_iter().enumerate()
+                        .fold((0, $float::INFINITY), |min, (i, c)| if c < min.1 { (i, c) } else { min });
+                    let mut perpendicular = Self::ZERO;
+                    perpendicular[axis] = 1.0;
+                    let perpendicular = perpendicular.reject_from(self).normalise() * self.length();
+                    return self * (t * angle).cos() + perpendicular * (t * angle).sin();
+                }
+                self * (((1.0 - t) * angle).sin() / sin) + other * ((t * angle).sin() / sin)
+            }
+        }
+    };
+}
+impl_geometric_functions!(Vec2, f32);
+impl_geometric_functions!(Vec3, f32);
+impl_geometric_functions!(Vec4, f32);
+impl_geometric_functions!(DVec2, f64);
+impl_geometric_functions!(DVec3, f64);
+impl_geometric_functions!(DVec4, f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn rounding() {
+        let a = vec4(-1.5, -0.25, 0.0, 2.75);
+        assert!(a.floor() == vec4(-2.0, -1.0, 0.0, 2.0));
+        assert!(a.ceil() == vec4(-1.0, -0.0, 0.0, 3.0));
+        assert!(a.round() == vec4(-2.0, -0.0, 0.0, 3.0));
+        assert!(a.trunc() == vec4(-1.0, -0.0, 0.0, 2.0));
+        assert!(a.fract() == vec4(0.5, 0.75, 0.0, 0.75));
+        assert!(a.sign() == vec4(-1.0, -1.0, 0.0, 1.0));
+    }
+    #[test]
+    fn glsl_mod() {
+        let a = dvec2(-1.0, 5.5);
+        assert!(a.modulo(DVec2::splat(2.0)) == dvec2(1.0, 1.5));
+        assert!(a % DVec2::splat(2.0) == dvec2(-1.0, 1.5));
+    }
+    #[test]
+    fn interpolation() {
+        let a = vec3(0.0, 1.0, 2.0);
+        let b = vec3(2.0, 3.0, 4.0);
+        assert!(a.mix(b, 0.25) == vec3(0.5, 1.5, 2.5));
+        assert!(a.step(Vec3::ONE) == vec3(0.0, 1.0, 1.0));
+        assert!(a.smoothstep(Vec3::ZERO, Vec3::splat(2.0)) == vec3(0.0, 0.5, 1.0));
+        assert!(b.clamp(Vec3::ZERO, Vec3::splat(3.0)) == vec3(2.0, 3.0, 3.0));
+        assert!(a.mix_each(b, vec3(0.0, 0.5, 1.0)) == vec3(0.0, 2.0, 4.0));
+        assert!(b.clamp_scalar(0.0, 3.0) == b.clamp(Vec3::ZERO, Vec3::splat(3.0)));
+        assert!(a.min_scalar(1.5) == vec3(0.0, 1.0, 1.5) && a.max_scalar(1.5) == vec3(1.5, 1.5, 2.0));
+        assert!(a.step_scalar(1.0) == a.step(Vec3::ONE));
+        assert!(dvec2(0.5, 3.0).smoothstep_scalar(0.0, 2.0) == dvec2(0.15625, 1.0));
+    }
+    #[test]
+    fn geometric() {
+        let a = vec3(1.0, -1.0, 0.0);
+        assert!(a.reflect(Vec3::Y) == vec3(1.0, 1.0, 0.0));
+        assert!(a.refract(Vec3::Y, 1.0) == a);
+        assert!(Vec3::Y.faceforward(a, Vec3::Y) == Vec3::Y);
+        assert!(a.project_onto(Vec3::X * 2.0) == Vec3::X);
+        assert!(a.reject_from(Vec3::X) == -Vec3::Y);
+        assert!((Vec3::X.angle_between(a) - std::f32::consts::FRAC_PI_4).abs() < 1e-6);
+        let half = dvec2(1.0, 0.0).slerp(dvec2(0.0, 1.0), 0.5);
+        assert!((half - DVec2::splat(0.5f64.sqrt())).length() < 1e-12);
+        // opposite vectors rotate through a perpendicular direction instead of shrinking to zero
+        let half = Vec3::X.slerp(-Vec3::X, 0.5);
+        assert!((half.length() - 1.0).abs() < 1e-6 && half.dot(Vec3::X).abs() < 1e-6);
+        assert!((Vec3::X.slerp(-Vec3::X, 1.0) + Vec3::X).length() < 1e-6);
+        let (a, b) = (dvec4(0.5, -0.5, 0.5, 0.5), dvec4(-0.5, 0.5, -0.5, -0.5));
+        for i in 0..=10 { assert!((a.slerp(b, i as f64 / 10.0).length() - 1.0).abs() < 1e-12); }
+        assert!(vec2(0.6, 0.8).slerp(vec2(0.6, 0.8), 0.5) == vec2(0.6, 0.8));
+        let n = vec3(0.3, -0.5, -0.7).normalise();
+        let (b, c) = n.any_orthonormal_basis();
+        assert!(n.dot(b).abs() < 1e-6 && n.dot(c).abs() < 1e-6 && b.dot(c).abs() < 1e-6);
+        assert!((b.length() - 1.0).abs() < 1e-6 && (c.length() - 1.0).abs() < 1e-6);
+    }
+}
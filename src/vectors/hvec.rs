@@ -0,0 +1,110 @@
+//! half precision vectors, used to store vertex data in half the memory. they are only for storage,
+//! so convert them to `Vec2`, `Vec3` or `Vec4` to do maths with them. converting to half precision
+//! rounds to the nearest `f16`, which is within 1 part in 2048 for values from about 6e-5 up to
+//! 65504. larger values become infinity.
+//! ```
+//! use glium_types::vectors::*;
+//! let uv = HVec2::from(vec2(0.25, 0.1));
+//! assert!(Vec2::from(uv).x == 0.25);
+//! assert!((Vec2::from(uv).y - 0.1).abs() < 1.0 / 4096.0);
+//! ```
+use glium::uniforms::{AsUniformValue, UniformValue};
+use half::f16;
+use super::*;
+
+macro_rules! impl_hvec {
+    ($($hvec:ident, $fn:ident, $vec:ident, $uniform:ident, $n:literal { $($c:ident)* };)*) => {
+        $(
+            #[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+            #[doc = concat!("a half precision vector with the same components as `", stringify!($vec), "`.")]
+            #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+            #[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+            #[repr(C)]
+            pub struct $hvec { $(pub $c: f16),* }
+            impl $hvec {
+                /// a zero vector
+                pub const ZERO: Self = Self::splat(f16::ZERO);
+                /// a vector full of ones
+                pub const ONE: Self = Self::splat(f16::ONE);
+
+                pub const fn new($($c: f16),*) -> Self { Self { $($c),* } }
+                /// create a vector where every component equals `value`.
+                pub const fn splat(value: f16) -> Self { Self { $($c: value),* } }
+            }
+            /// create a half precision vector from `f16` components.
+            pub const fn $fn($($c: f16),*) -> $hvec { $hvec { $($c),* } }
+            /// rounds each component to the nearest `f16`.
+            impl From<$vec> for $hvec {
+                fn from(value: $vec) -> Self { Self { $($c: f16::from_f32(value.$c)),* } }
+            }
+            impl From<$hvec> for $vec {
+                fn from(value: $hvec) -> Self { Self { $($c: value.$c.to_f32()),* } }
+            }
+            impl From<[f16; $n]> for $hvec {
+                fn from([$($c),*]: [f16; $n]) -> Self { Self { $($c),* } }
+            }
+            impl From<$hvec> for [f16; $n] {
+                fn from(value: $hvec) -> Self { [$(value.$c),*] }
+            }
+            /// glsl has no half precision uniforms, so it is sent as a float vector.
+            impl AsUniformValue for $hvec {
+                fn as_uniform_value(&self) -> UniformValue<'_> {
+                    UniformValue::$uniform([$(self.$c.to_f32()),*])
+                }
+            }
+        )*
+    };
+}
+impl_hvec!(
+    HVec2, hvec2, Vec2, Vec2, 2 { x y };
+    HVec3, hvec3, Vec3, Vec3, 3 { x y z };
+    HVec4, hvec4, Vec4, Vec4, 4 { x y z w };
+);
+
+#[cfg(test)]
+mod tests {
+    use glium::{implement_vertex, Vertex, vertex::AttributeType};
+    use super::*;
+    #[derive(Clone, Copy)]
+    struct CompressedVertex {
+        position: HVec3,
+        uv: HVec2,
+    }
+    implement_vertex!(CompressedVertex, position, uv);
+    #[test]
+    fn vertex_format() {
+        let format = CompressedVertex::build_bindings();
+        assert_eq!(format[0].3, AttributeType::F16F16F16);
+        let uv = ("uv", std::mem::offset_of!(CompressedVertex, uv), AttributeType::F16F16);
+        assert_eq!((format[1].0.as_ref(), format[1].1, format[1].3), uv);
+        assert_eq!(std::mem::size_of::<CompressedVertex>(), 10);
+        assert_eq!(AttributeType::F16F16F16F16.get_size_bytes(), std::mem::size_of::<HVec4>());
+    }
+    #[test]
+    fn quantisation_error() {
+        // positions over a large range keep 11 bits of precision
+        let mut x = 6.2e-5f32;
+        while x < 65504.0 {
+            let v = vec3(x, -x, x * 0.7);
+            let error = Vec3::from(HVec3::from(v)) - v;
+            assert!(error.abs().max_element() <= v.abs().max_element() / 2048.0, "{v:?} {error:?}");
+            x *= 1.37;
+        }
+        // uvs from 0 to 1 are within half of the gap between f16s near 1
+        for i in 0..=1000 {
+            let uv = vec2(i as f32 / 1000.0, 1.0 - i as f32 / 1000.0);
+            let error = Vec2::from(HVec2::from(uv)) - uv;
+            assert!(error.abs().max_element() <= 1.0 / 4096.0, "{uv:?} {error:?}");
+        }
+        // whole numbers up to 2048 are exact
+        let v = vec4(0.0, 1.0, -2047.0, 2048.0);
+        assert_eq!(Vec4::from(HVec4::from(v)), v);
+        assert_eq!(HVec2::from(vec2(1e5, -1e5)), hvec2(f16::INFINITY, f16::NEG_INFINITY));
+    }
+    #[test]
+    fn uniforms() {
+        let v = HVec3::from(vec3(1.0, 0.5, -2.0));
+        assert!(matches!(v.as_uniform_value(), UniformValue::Vec3([1.0, 0.5, -2.0])));
+        assert_eq!(<[f16; 3]>::from(v), [f16::ONE, f16::from_f32(0.5), f16::from_f32(-2.0)]);
+    }
+}
@@ -0,0 +1,209 @@
+//! overflow aware arithmetic, bitwise operators and bit counting for the interger vectors. each
+//! function works on every component separately and matches the interger function with the same
+//! name.
+use paste::paste;
+use super::*;
+
+macro_rules! impl_overflow_ops {
+    ($vec:ident, $bvec:ident; $components:tt; $($op:ident $name:literal),*) => {
+        $( impl_overflow_ops!(@op $vec, $bvec; $op $name; $components); )*
+    };
+    (@op $vec:ident, $bvec:ident; $op:ident $name:literal; { $($c:ident)* }) => {
+        paste! {
+            #[doc = concat!("component wise ", $name, " that returns `None` if any component overflows.")]
+            pub fn [<checked_ $op>](self, rhs: Self) -> Option<Self> {
+                Some(Self { $($c: self.$c.[<checked_ $op>](rhs.$c)?),* })
+            }
+            #[doc = concat!("component wise ", $name, " that wraps around on overflow.")]
+            pub fn [<wrapping_ $op>](self, rhs: Self) -> Self {
+                Self { $($c: self.$c.[<wrapping_ $op>](rhs.$c)),* }
+            }
+            #[doc = concat!("component wise ", $name, " that returns the wrapped result and which ")]
+            #[doc = "components overflowed."]
+            pub fn [<overflowing_ $op>](self, rhs: Self) -> (Self, $bvec) {
+                $( let $c = self.$c.[<overflowing_ $op>](rhs.$c); )*
+                (Self { $($c: $c.0),* }, $bvec::new($($c.1),*))
+            }
+        }
+    };
+}
+macro_rules! impl_saturating_ops {
+    ($vec:ident; $components:tt; $($op:ident $name:literal),*) => {
+        $( impl_saturating_ops!(@op $vec; $op $name; $components); )*
+    };
+    (@op $vec:ident; $op:ident $name:literal; { $($c:ident)* }) => {
+        paste! {
+            #[doc = concat!("component wise ", $name, " that stops at the min or max value instead of overflowing.")]
+            pub fn [<saturating_ $op>](self, rhs: Self) -> Self {
+                Self { $($c: self.$c.[<saturating_ $op>](rhs.$c)),* }
+            }
+        }
+    };
+}
+macro_rules! impl_integer_functions {
+    ($vec:ident, $bvec:ident, $unsigned:ident; $($c:ident)*) => {
+        impl $vec {
+            impl_overflow_ops!($vec, $bvec; { $($c)* };
+                add "addition", sub "subtraction", mul "multiplication", div "division", rem "remainder");
+            impl_saturating_ops!($vec; { $($c)* };
+                add "addition", sub "subtraction", mul "multiplication", div "division");
+            /// component wise euclidean division. rounds so that the remainder is always positive.
+            pub fn div_euclid(self, rhs: Self) -> Self { Self { $($c: self.$c.div_euclid(rhs.$c)),* } }
+            /// component wise euclidean remainder. the result is never negative.
+            pub fn rem_euclid(self, rhs: Self) -> Self { Self { $($c: self.$c.rem_euclid(rhs.$c)),* } }
+            /// the absolute difference of each component. never overflows.
+            pub fn abs_diff(self, other: Self) -> $unsigned {
+                $unsigned { $($c: self.$c.abs_diff(other.$c)),* }
+            }
+        }
+    };
+}
+impl_integer_functions!(IVec2, BVec2, UVec2; x y);
+impl_integer_functions!(IVec3, BVec3, UVec3; x y z);
+impl_integer_functions!(IVec4, BVec4, UVec4; x y z w);
+impl_integer_functions!(UVec2, BVec2, UVec2; x y);
+impl_integer_functions!(UVec3, BVec3, UVec3; x y z);
+impl_integer_functions!(UVec4, BVec4, UVec4; x y z w);
+impl_integer_functions!(DIVec2, BVec2, DUVec2; x y);
+impl_integer_functions!(DIVec3, BVec3, DUVec3; x y z);
+impl_integer_functions!(DIVec4, BVec4, DUVec4; x y z w);
+impl_integer_functions!(DUVec2, BVec2, DUVec2; x y);
+impl_integer_functions!(DUVec3, BVec3, DUVec3; x y z);
+impl_integer_functions!(DUVec4, BVec4, DUVec4; x y z w);
+
+macro_rules! impl_bit_op {
+    (
+        $(#[$doc:meta])* $vec:ident, $int:ident;
+        $trait:ident $fn:ident $op:tt, $assign_trait:ident $assign_fn:ident; $($c:ident)*
+    ) => {
+        $(#[$doc])*
+        impl std::ops::$trait for $vec {
+            fn $fn(self, rhs: Self) -> Self::Output { Self { $($c: self.$c $op rhs.$c),* } }
+            type Output = Self;
+        }
+        $(#[$doc])*
+        impl std::ops::$trait<$int> for $vec {
+            fn $fn(self, rhs: $int) -> Self::Output { Self { $($c: self.$c $op rhs),* } }
+            type Output = Self;
+        }
+        $(#[$doc])*
+        impl std::ops::$trait<$vec> for $int {
+            fn $fn(self, rhs: $vec) -> Self::Output { $vec { $($c: self $op rhs.$c),* } }
+            type Output = $vec;
+        }
+        $(#[$doc])*
+        impl std::ops::$assign_trait for $vec {
+            fn $assign_fn(&mut self, rhs: Self) { *self = *self $op rhs }
+        }
+        $(#[$doc])*
+        impl std::ops::$assign_trait<$int> for $vec {
+            fn $assign_fn(&mut self, rhs: $int) { *self = *self $op rhs }
+        }
+    };
+}
+macro_rules! impl_bit_ops {
+    ($vec:ident, $int:ident, $uvec:ident; $($c:ident)*) => {
+        impl_bit_op!($vec, $int; BitAnd bitand &, BitAndAssign bitand_assign; $($c)*);
+        impl_bit_op!($vec, $int; BitOr bitor |, BitOrAssign bitor_assign; $($c)*);
+        impl_bit_op!($vec, $int; BitXor bitxor ^, BitXorAssign bitxor_assign; $($c)*);
+        impl_bit_op!(
+            /// panics in debug if a shift is negative or not less than the number of bits. use
+            /// `wrapping_shl` to mask the shift instead.
+            $vec, $int; Shl shl <<, ShlAssign shl_assign; $($c)*
+        );
+        impl_bit_op!(
+            /// panics in debug if a shift is negative or not less than the number of bits. use
+            /// `wrapping_shr` to mask the shift instead.
+            $vec, $int; Shr shr >>, ShrAssign shr_assign; $($c)*
+        );
+        impl std::ops::Not for $vec {
+            fn not(self) -> Self::Output { Self { $($c: !self.$c),* } }
+            type Output = Self;
+        }
+        impl $vec {
+            /// the number of ones in the binary representation of each component.
+            pub fn count_ones(self) -> $uvec { $uvec { $($c: self.$c.count_ones()),* } }
+            /// the number of zeros before the first one in the binary representation of each
+            /// component.
+            pub fn leading_zeros(self) -> $uvec { $uvec { $($c: self.$c.leading_zeros()),* } }
+            /// the number of zeros after the last one in the binary representation of each
+            /// component.
+            pub fn trailing_zeros(self) -> $uvec { $uvec { $($c: self.$c.trailing_zeros()),* } }
+            /// shifts each component left by the matching component of `rhs`, modulo the number of
+            /// bits.
+            pub fn wrapping_shl(self, rhs: $uvec) -> Self { Self { $($c: self.$c.wrapping_shl(rhs.$c)),* } }
+            /// shifts each component right by the matching component of `rhs`, modulo the number of
+            /// bits.
+            pub fn wrapping_shr(self, rhs: $uvec) -> Self { Self { $($c: self.$c.wrapping_shr(rhs.$c)),* } }
+        }
+    };
+}
+impl_bit_ops!(IVec2, i32, UVec2; x y);
+impl_bit_ops!(IVec3, i32, UVec3; x y z);
+impl_bit_ops!(IVec4, i32, UVec4; x y z w);
+impl_bit_ops!(UVec2, u32, UVec2; x y);
+impl_bit_ops!(UVec3, u32, UVec3; x y z);
+impl_bit_ops!(UVec4, u32, UVec4; x y z w);
+impl_bit_ops!(DIVec2, i64, UVec2; x y);
+impl_bit_ops!(DIVec3, i64, UVec3; x y z);
+impl_bit_ops!(DIVec4, i64, UVec4; x y z w);
+impl_bit_ops!(DUVec2, u64, UVec2; x y);
+impl_bit_ops!(DUVec3, u64, UVec3; x y z);
+impl_bit_ops!(DUVec4, u64, UVec4; x y z w);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn overflow() {
+        let a = uvec3(1, 5, u32::MAX);
+        let b = uvec3(2, 3, 1);
+        assert!(a.checked_sub(b).is_none());
+        assert!(a.checked_sub(uvec3(1, 1, 1)) == Some(uvec3(0, 4, u32::MAX - 1)));
+        assert!(a.wrapping_sub(b) == uvec3(u32::MAX, 2, u32::MAX - 1));
+        assert!(a.saturating_add(b) == uvec3(3, 8, u32::MAX));
+        let (result, overflowed) = a.overflowing_add(b);
+        assert!(result == uvec3(3, 8, 0));
+        assert!(overflowed.bitmask() == 0b100);
+        assert!(ivec2(i32::MIN, 0).checked_div(ivec2(-1, 1)).is_none());
+        assert!(ivec2(1, 1).checked_rem(ivec2(1, 0)).is_none());
+    }
+    #[test]
+    fn euclid() {
+        let a = divec3(-7, 7, -1);
+        let b = DIVec3::splat(4);
+        assert!(a.div_euclid(b) == divec3(-2, 1, -1));
+        assert!(a.rem_euclid(b) == divec3(1, 3, 3));
+        assert!(ivec2(i32::MIN, 3).abs_diff(ivec2(i32::MAX, -3)) == uvec2(u32::MAX, 6));
+    }
+    #[test]
+    fn bitwise() {
+        let a = uvec4(0b1100, 0b1010, 1, u32::MAX);
+        let b = uvec4(0b1010, 0b0110, 1, 0);
+        assert!(a & b == uvec4(0b1000, 0b0010, 1, 0));
+        assert!(a | b == uvec4(0b1110, 0b1110, 1, u32::MAX));
+        assert!(a ^ b == uvec4(0b0110, 0b1100, 0, u32::MAX));
+        assert!(!b == uvec4(!0b1010, !0b0110, !1, u32::MAX));
+        assert!(a >> 2 == uvec4(0b11, 0b10, 0, u32::MAX >> 2));
+        assert!(1 << uvec4(0, 1, 2, 3) == uvec4(1, 2, 4, 8));
+        assert!(ivec2(1, -8).wrapping_shl(uvec2(33, 1)) == ivec2(2, -16));
+        assert!(duvec2(8, 8).wrapping_shr(uvec2(64, 67)) == duvec2(8, 1));
+        let mut c = ivec2(-1, 6);
+        c <<= ivec2(1, 2);
+        assert!(c == ivec2(-2, 24));
+        assert!(c.count_ones() == uvec2(31, 2));
+        assert!(c.leading_zeros() == uvec2(0, 27));
+        assert!(divec2(8, 0).trailing_zeros() == uvec2(3, 64));
+    }
+    #[test]
+    fn keys() {
+        use std::collections::{BTreeSet, HashSet};
+        let tiles: HashSet<IVec2> = [ivec2(1, 2), ivec2(1, 2), ivec2(2, 1)].into_iter().collect();
+        assert!(tiles.len() == 2 && tiles.contains(&ivec2(2, 1)));
+        let ordered: Vec<_> = BTreeSet::from([uvec3(1, 0, 0), uvec3(0, 5, 5), uvec3(0, 5, 1)])
+            .into_iter().collect();
+        assert!(ordered == [uvec3(0, 5, 1), uvec3(0, 5, 5), uvec3(1, 0, 0)]);
+        assert!(bvec2(false, true) < bvec2(true, false));
+        assert!(BVec3::default() == BVec3::FALSE && DIVec4::default() == DIVec4::ZERO);
+    }
+}
@@ -0,0 +1,161 @@
+//! indexing, iterating and collecting the components of vectors, and functions that combine them.
+//! ```
+//! use glium_types::vectors::*;
+//! let verts = [vec3(0.0, 0.0, 0.0), vec3(2.0, 0.0, 0.0), vec3(1.0, 3.0, 0.0)];
+//! let centre = verts.iter().copied().sum::<Vec3>() / verts.len() as f32;
+//! assert!(centre == vec3(1.0, 1.0, 0.0));
+//! assert!(centre.into_iter().collect::<Vec<_>>() == [1.0, 1.0, 0.0]);
+//! assert!(centre[1] == 1.0 && centre.max_element() == 1.0);
+//! ```
+use std::{array::TryFromSliceError, iter::{Product, Sum}, ops::{Index, IndexMut}};
+use super::*;
+
+macro_rules! impl_iter {
+    ($vec:ident, $scalar:ty, $n:literal; $($i:literal $c:ident)*) => {
+        impl $vec {
+            /// applies `f` to each component.
+            pub fn map(self, mut f: impl FnMut($scalar) -> $scalar) -> Self { Self { $($c: f(self.$c)),* } }
+            /// applies `f` to each component and the matching component of `other`.
+            pub fn zip_map(self, other: Self, mut f: impl FnMut($scalar, $scalar) -> $scalar) -> Self {
+                Self { $($c: f(self.$c, other.$c)),* }
+            }
+        }
+        impl Index<usize> for $vec {
+            type Output = $scalar;
+            /// panics if `index` is not less than the number of components.
+            fn index(&self, index: usize) -> &$scalar {
+                match index {
+                    $($i => &self.$c,)*
+                    _ => panic!("index out of bounds: {} has {} components but the index is {index}", stringify!($vec), $n)
+                }
+            }
+        }
+        impl IndexMut<usize> for $vec {
+            fn index_mut(&mut self, index: usize) -> &mut $scalar {
+                match index {
+                    $($i => &mut self.$c,)*
+                    _ => panic!("index out of bounds: {} has {} components but the index is {index}", stringify!($vec), $n)
+                }
+            }
+        }
+        impl IntoIterator for $vec {
+            type Item = $scalar;
+            type IntoIter = std::array::IntoIter<$scalar, $n>;
+            fn into_iter(self) -> Self::IntoIter { [$(self.$c),*].into_iter() }
+        }
+        /// panics if the iterator doesn't have exactly as many items as there are components.
+        impl FromIterator<$scalar> for $vec {
+            fn from_iter<I: IntoIterator<Item = $scalar>>(iter: I) -> Self {
+                let mut iter = iter.into_iter();
+                let (mut components, mut count) = ([<$scalar>::default(); $n], 0);
+                for component in iter.by_ref().take($n) {
+                    components[count] = component;
+                    count += 1;
+                }
+                if count != $n || iter.next().is_some() {
+                    let got = if count != $n { "fewer" } else { "more" };
+                    panic!("{} needs {} components, got {got}", stringify!($vec), $n)
+                }
+                let [$($c),*] = components;
+                Self { $($c),* }
+            }
+        }
+        /// fails if the slice doesn't have exactly as many items as there are components.
+        impl TryFrom<&[$scalar]> for $vec {
+            type Error = TryFromSliceError;
+            fn try_from(value: &[$scalar]) -> Result<Self, TryFromSliceError> {
+                let [$($c),*]: [$scalar; $n] = value.try_into()?;
+                Ok(Self { $($c),* })
+            }
+        }
+    };
+}
+macro_rules! impl_reduce {
+    ($vec:ident, $scalar:ty; $first:ident $($c:ident)*) => {
+        impl $vec {
+            /// the smallest component.
+            pub fn min_element(self) -> $scalar { self.$first$(.min(self.$c))* }
+            /// the largest component.
+            pub fn max_element(self) -> $scalar { self.$first$(.max(self.$c))* }
+            /// all of the components added together.
+            pub fn sum(self) -> $scalar { self.$first $(+ self.$c)* }
+            /// all of the components multiplied together.
+            pub fn product(self) -> $scalar { self.$first $(* self.$c)* }
+        }
+        impl Sum for $vec {
+            fn sum<I: Iterator<Item = Self>>(iter: I) -> Self { iter.fold(Self::ZERO, |a, b| a + b) }
+        }
+        impl<'a> Sum<&'a $vec> for $vec {
+            fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self { iter.copied().sum() }
+        }
+        impl Product for $vec {
+            fn product<I: Iterator<Item = Self>>(iter: I) -> Self { iter.fold(Self::ONE, |a, b| a * b) }
+        }
+        impl<'a> Product<&'a $vec> for $vec {
+            fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self { iter.copied().product() }
+        }
+    };
+}
+macro_rules! impl_vector_iter {
+    ($($vec2:ident, $vec3:ident, $vec4:ident, $scalar:ty;)*) => {
+        $(
+            impl_iter!($vec2, $scalar, 2; 0 x 1 y);
+            impl_iter!($vec3, $scalar, 3; 0 x 1 y 2 z);
+            impl_iter!($vec4, $scalar, 4; 0 x 1 y 2 z 3 w);
+            impl_reduce!($vec2, $scalar; x y);
+            impl_reduce!($vec3, $scalar; x y z);
+            impl_reduce!($vec4, $scalar; x y z w);
+        )*
+    };
+}
+impl_vector_iter!(
+    Vec2, Vec3, Vec4, f32; DVec2, DVec3, DVec4, f64;
+    IVec2, IVec3, IVec4, i32; UVec2, UVec3, UVec4, u32;
+    DIVec2, DIVec3, DIVec4, i64; DUVec2, DUVec3, DUVec4, u64;
+);
+impl_iter!(BVec2, bool, 2; 0 x 1 y);
+impl_iter!(BVec3, bool, 3; 0 x 1 y 2 z);
+impl_iter!(BVec4, bool, 4; 0 x 1 y 2 z 3 w);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn indexing() {
+        let mut a = ivec4(1, 2, 3, 4);
+        assert!([a[0], a[1], a[2], a[3]] == [1, 2, 3, 4]);
+        a[2] = 7;
+        assert!(a == ivec4(1, 2, 7, 4));
+        let mut b = BVec2::FALSE;
+        b[1] = true;
+        assert!(b == BVec2::Y);
+        assert!(std::panic::catch_unwind(|| vec3(1.0, 2.0, 3.0)[3]).is_err());
+    }
+    #[test]
+    fn iterating() {
+        let a = dvec3(1.0, -2.0, 4.0);
+        assert!(a.into_iter().collect::<Vec<_>>() == [1.0, -2.0, 4.0]);
+        assert!(a.into_iter().collect::<DVec3>() == a);
+        assert!(a.into_iter().rev().collect::<DVec3>() == dvec3(4.0, -2.0, 1.0));
+        assert!(UVec2::try_from([1, 2].as_slice()).unwrap() == uvec2(1, 2));
+        assert!(UVec2::try_from([1, 2, 3].as_slice()).is_err());
+        assert!(std::panic::catch_unwind(|| [1.0].into_iter().collect::<Vec2>()).is_err());
+        assert!(std::panic::catch_unwind(|| (0..5).collect::<IVec4>()).is_err());
+        // stops after one extra item instead of draining the iterator
+        assert!(std::panic::catch_unwind(|| std::iter::repeat(1.0).collect::<Vec2>()).is_err());
+        assert!((0..4).collect::<UVec4>() == uvec4(0, 1, 2, 3));
+    }
+    #[test]
+    fn reducing() {
+        let a = vec4(3.0, -1.0, 2.0, 0.5);
+        assert!(a.min_element() == -1.0 && a.max_element() == 3.0);
+        assert!(a.sum() == 4.5 && a.product() == -3.0);
+        assert!(a.map(|c| c * 2.0) == a * 2.0);
+        assert!(a.zip_map(Vec4::ONE, f32::max) == vec4(3.0, 1.0, 2.0, 1.0));
+        assert!(divec2(i64::MIN, 5).min_element() == i64::MIN);
+        let verts = [uvec3(1, 2, 3), uvec3(4, 5, 6)];
+        assert!(verts.iter().sum::<UVec3>() == uvec3(5, 7, 9));
+        assert!(verts.into_iter().product::<UVec3>() == uvec3(4, 10, 18));
+        assert!(std::iter::empty::<Vec2>().sum::<Vec2>() == Vec2::ZERO);
+    }
+}
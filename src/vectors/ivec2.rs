@@ -2,8 +2,11 @@ use derive_cmp_ops::CmpOps;
 use glium::uniforms::AsUniformValue;
 
 use super::{ivec3::*, vec2::Vec2, bvec2::*};
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, CmpOps)]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash, Default, CmpOps)]
 /// an interger vector made from a x and y coordinate.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[repr(C)]
 pub struct IVec2 {
     pub x: i32,
     pub y: i32,
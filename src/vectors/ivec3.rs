@@ -1,8 +1,11 @@
 use derive_cmp_ops::CmpOps;
 use glium::uniforms::AsUniformValue;
 use super::{vec3::Vec3, ivec2::*, ivec4::*, bvec3::*};
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, CmpOps)]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash, Default, CmpOps)]
 /// an interger vector made from a x, y and z coordinate.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[repr(C)]
 pub struct IVec3 {
     pub x: i32,
     pub y: i32,
@@ -0,0 +1,55 @@
+//! picking components from 2 vectors with a boolean vector, like the comparison functions return.
+use super::*;
+
+macro_rules! impl_select {
+    ($(#[$example:meta])* $vec:ident, $mask:ident; $($c:ident)*) => {
+        impl $vec {
+            /// picks the components of `b` where `mask` is true and the components of `a` where
+            /// it's false. the same as glsl `mix(a, b, mask)`
+            $(#[$example])*
+            pub fn select(mask: $mask, a: Self, b: Self) -> Self {
+                Self { $($c: if mask.$c { b.$c } else { a.$c }),* }
+            }
+        }
+    };
+}
+impl_select!(Vec2, BVec2; x y);
+impl_select!(
+    /// ```
+    /// use glium_types::vectors::{Vec3, vec3};
+    /// let a = vec3(1.0, -2.0, 3.0);
+    /// // branch free abs
+    /// let abs = Vec3::select(a.less(Vec3::ZERO), a, -a);
+    /// assert!(abs == vec3(1.0, 2.0, 3.0));
+    /// ```
+    Vec3, BVec3; x y z
+);
+impl_select!(Vec4, BVec4; x y z w);
+impl_select!(DVec2, BVec2; x y);
+impl_select!(DVec3, BVec3; x y z);
+impl_select!(DVec4, BVec4; x y z w);
+impl_select!(IVec2, BVec2; x y);
+impl_select!(IVec3, BVec3; x y z);
+impl_select!(IVec4, BVec4; x y z w);
+impl_select!(UVec2, BVec2; x y);
+impl_select!(UVec3, BVec3; x y z);
+impl_select!(UVec4, BVec4; x y z w);
+impl_select!(DIVec2, BVec2; x y);
+impl_select!(DIVec3, BVec3; x y z);
+impl_select!(DIVec4, BVec4; x y z w);
+impl_select!(DUVec2, BVec2; x y);
+impl_select!(DUVec3, BVec3; x y z);
+impl_select!(DUVec4, BVec4; x y z w);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn selecting() {
+        let (a, b) = (ivec3(1, -2, 3), ivec3(4, 5, -6));
+        assert!(IVec3::select(a.less(b), a, b) == ivec3(4, 5, 3));
+        assert!(IVec3::select(BVec3::FALSE, a, b) == a && IVec3::select(BVec3::TRUE, a, b) == b);
+        assert!(Vec2::select(BVec2::Y, vec2(1.0, 2.0), vec2(3.0, 4.0)) == vec2(1.0, 4.0));
+        assert!(DUVec4::select(bvec4(true, false, false, true), DUVec4::ZERO, DUVec4::ONE) == duvec4(1, 0, 0, 1));
+    }
+}
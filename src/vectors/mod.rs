@@ -40,3 +40,44 @@ mod duvec4;
 pub use duvec2::{DUVec2, duvec2};
 pub use duvec3::{DUVec3, duvec3};
 pub use duvec4::{DUVec4, duvec4};
+#[cfg(feature = "half")]
+mod hvec;
+#[cfg(feature = "half")]
+pub use hvec::*;
+mod norm;
+pub use norm::*;
+mod packing;
+pub use packing::*;
+mod swizzle;
+mod functions;
+mod integer;
+mod iter;
+mod mask;
+#[cfg(feature = "simd")]
+mod simd;
+
+#[cfg(all(test, feature = "serde"))]
+mod serde_tests {
+    use super::*;
+    #[test]
+    fn json() {
+        use crate::testing::serde_round_trip;
+        serde_round_trip(vec3(1.0, 2.5, -3.0), r#"{"x":1.0,"y":2.5,"z":-3.0}"#);
+        serde_round_trip(duvec2(1, u64::MAX), r#"{"x":1,"y":18446744073709551615}"#);
+        serde_round_trip(bvec4(true, false, true, false), r#"{"x":true,"y":false,"z":true,"w":false}"#);
+    }
+}
+
+#[cfg(all(test, feature = "bytemuck"))]
+mod bytemuck_tests {
+    use super::*;
+    #[test]
+    fn casting() {
+        let positions = [vec3(1.0, 2.0, 3.0), vec3(4.0, 5.0, 6.0)];
+        let floats: &[f32] = bytemuck::cast_slice(&positions);
+        assert_eq!(floats, [1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        assert_eq!(bytemuck::bytes_of(&positions[0]).len(), 12);
+        assert_eq!(bytemuck::cast::<_, [u64; 3]>(duvec3(1, 2, 3)), [1, 2, 3]);
+        assert_eq!(<BVec4 as bytemuck::Zeroable>::zeroed(), BVec4::FALSE);
+    }
+}
@@ -0,0 +1,106 @@
+//! normalized integer vectors, used to store vertex data that is in a fixed range in fewer bytes.
+//! unorm types store 0.0 to 1.0 and snorm types store -1.0 to 1.0, using the whole range of the
+//! integer. converting from float vectors clamps each component to the range and rounds it to the
+//! nearest step. use `normalize(true)` in `implement_vertex!` so the shader gets the float values.
+//! ```
+//! use glium_types::vectors::*;
+//! let colour = Unorm8x4::from(vec4(1.0, 0.5, -1.0, 2.0));
+//! assert!(colour == Unorm8x4::new(255, 128, 0, 255));
+//! let normal = Snorm16x3::from(vec3(0.0, -1.0, 0.5));
+//! assert!(Vec3::from(normal).y == -1.0);
+//! ```
+use super::*;
+
+macro_rules! impl_norm {
+    (@encode unorm $value:expr, $int:ty) => { ($value.clamp(0.0, 1.0) * <$int>::MAX as f32).round() as $int };
+    (@encode snorm $value:expr, $int:ty) => { ($value.clamp(-1.0, 1.0) * <$int>::MAX as f32).round() as $int };
+    (@decode unorm $value:expr, $int:ty) => { $value as f32 / <$int>::MAX as f32 };
+    // the smallest value is below -1.0, so it is treated as -1.0 the same as opengl does
+    (@decode snorm $value:expr, $int:ty) => { ($value as f32 / <$int>::MAX as f32).max(-1.0) };
+    ($($norm:ident, $kind:ident, $int:ty, $vec:ident, $n:literal { $($c:ident)* };)*) => {
+        $(
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+            #[doc = concat!(
+                "a ", stringify!($kind), " vector stored in `", stringify!($int), "`s, that converts to and from `",
+                stringify!($vec), "`."
+            )]
+            #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+            #[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+            #[repr(C)]
+            pub struct $norm { $(pub $c: $int),* }
+            impl $norm {
+                /// create a vector from the stored integers.
+                pub const fn new($($c: $int),*) -> Self { Self { $($c),* } }
+            }
+            /// clamps each component to the range and rounds it to the nearest step.
+            impl From<$vec> for $norm {
+                fn from(value: $vec) -> Self { Self { $($c: impl_norm!(@encode $kind value.$c, $int)),* } }
+            }
+            impl From<$norm> for $vec {
+                fn from(value: $norm) -> Self { Self { $($c: impl_norm!(@decode $kind value.$c, $int)),* } }
+            }
+            impl From<[$int; $n]> for $norm {
+                fn from([$($c),*]: [$int; $n]) -> Self { Self { $($c),* } }
+            }
+            impl From<$norm> for [$int; $n] {
+                fn from(value: $norm) -> Self { [$(value.$c),*] }
+            }
+        )*
+    };
+}
+impl_norm!(
+    Unorm8x2, unorm, u8, Vec2, 2 { x y }; Unorm8x3, unorm, u8, Vec3, 3 { x y z };
+    Unorm8x4, unorm, u8, Vec4, 4 { x y z w };
+    Snorm8x2, snorm, i8, Vec2, 2 { x y }; Snorm8x3, snorm, i8, Vec3, 3 { x y z };
+    Snorm8x4, snorm, i8, Vec4, 4 { x y z w };
+    Unorm16x2, unorm, u16, Vec2, 2 { x y }; Unorm16x3, unorm, u16, Vec3, 3 { x y z };
+    Unorm16x4, unorm, u16, Vec4, 4 { x y z w };
+    Snorm16x2, snorm, i16, Vec2, 2 { x y }; Snorm16x3, snorm, i16, Vec3, 3 { x y z };
+    Snorm16x4, snorm, i16, Vec4, 4 { x y z w };
+);
+
+#[cfg(test)]
+mod tests {
+    use glium::{Vertex, vertex::AttributeType};
+    use crate::vert_types::{PackedNormal, PackedTextureCoords, PackedVertexColour};
+    use super::*;
+    #[test]
+    fn rounding() {
+        // every stored value converts back to itself
+        for i in 0..=u8::MAX {
+            assert!(Unorm8x2::from(Vec2::from(Unorm8x2::new(i, i))) == Unorm8x2::new(i, i));
+            let s = i as i8;
+            let expected = if s == i8::MIN { -127 } else { s };
+            assert!(Snorm8x2::from(Vec2::from(Snorm8x2::new(s, s))) == Snorm8x2::new(expected, expected));
+        }
+        // and converting to it is within half a step
+        for i in 0..=1000 {
+            let v = i as f32 / 1000.0;
+            let error = Vec2::from(Unorm8x2::from(vec2(v, -v))) - vec2(v, 0.0);
+            assert!(error.abs().max_element() <= 0.5 / 255.0 + f32::EPSILON);
+            let error = Vec2::from(Snorm16x2::from(vec2(v, -v))) - vec2(v, -v);
+            assert!(error.abs().max_element() <= 0.5 / 32767.0 + f32::EPSILON);
+        }
+        assert!(Unorm16x3::from(vec3(0.5, 1.0, 0.0)) == Unorm16x3::new(32768, 65535, 0));
+        assert!(Snorm8x4::from(vec4(0.5, -0.5, 1.0, -1.0)) == Snorm8x4::new(64, -64, 127, -127));
+    }
+    #[test]
+    fn clamping() {
+        assert!(Unorm8x4::from(vec4(-0.5, 1.5, f32::INFINITY, f32::NEG_INFINITY)) == Unorm8x4::new(0, 255, 255, 0));
+        assert!(Snorm16x2::from(vec2(-3.0, 3.0)) == Snorm16x2::new(-32767, 32767));
+        assert!(Vec4::from(Snorm8x4::new(i8::MIN, -127, 0, 127)) == vec4(-1.0, -1.0, 0.0, 1.0));
+    }
+    #[test]
+    fn vertex_formats() {
+        let [(name, _, _, ty, normalize)] = PackedNormal::build_bindings() else { panic!() };
+        assert_eq!((name.as_ref(), *ty, *normalize), ("normal", AttributeType::I16I16I16, true));
+        let [(name, _, _, ty, normalize)] = PackedTextureCoords::build_bindings() else { panic!() };
+        assert_eq!((name.as_ref(), *ty, *normalize), ("texture_coords", AttributeType::U16U16, true));
+        let [(name, _, _, ty, normalize)] = PackedVertexColour::build_bindings() else { panic!() };
+        assert_eq!((name.as_ref(), *ty, *normalize), ("colour", AttributeType::U8U8U8U8, true));
+        assert_eq!(std::mem::size_of::<PackedNormal>(), 6);
+        assert_eq!(std::mem::size_of::<PackedVertexColour>(), 4);
+        let colour = PackedVertexColour::from(vec4(1.0, 0.0, 0.5, 1.0));
+        assert!(colour.colour == Unorm8x4::new(255, 0, 128, 255));
+    }
+}
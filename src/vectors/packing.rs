@@ -0,0 +1,245 @@
+//! standard gpu packings of vectors into fewer bytes.
+//! - `Int2101010Rev` stores a `Vec4` from -1.0 to 1.0 in 32 bits, with 10 bits for x, y and z and
+//!   2 bits for w. glium can bind it as a vertex attribute with `normalize(true)`.
+//! - octahedral encoding stores a normalised `Vec3` in a `Snorm16x2` by folding the sphere onto a
+//!   square. it has to be decoded in the shader with `shaders::OCTAHEDRAL_DECODE`.
+//! - `Rgb9e5` stores a positive `Vec3` in 32 bits, with 9 bits for each component and a shared
+//!   5 bit exponent. it is the same as the opengl `GL_RGB9_E5` format.
+//! - `Rgbe` stores a positive `Vec3` in 4 bytes, with 8 bits for each component and a shared 8 bit
+//!   exponent. it is the format used by radiance `.hdr` files.
+//!
+//! ```
+//! use glium_types::vectors::*;
+//! let normal = vec3(0.48, -0.6, 0.64);
+//! let decoded = Vec3::from_octahedral(normal.to_octahedral());
+//! assert!(decoded.distance(normal) < 1e-4);
+//! let light = vec3(1000.0, 20.0, 0.5);
+//! assert!(Vec3::from_rgbe(light.to_rgbe()).distance(light) < 1000.0 / 256.0);
+//! ```
+use super::*;
+
+/// a `Vec4` packed into the `GL_INT_2_10_10_10_REV` format. x is in the lowest 10 bits, then y, z
+/// and w in the highest 2 bits, all as normalized signed integers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[repr(transparent)]
+pub struct Int2101010Rev(pub u32);
+/// a positive `Vec3` packed into the `GL_RGB9_E5` format. r, g and b are 9 bit mantissas from the
+/// lowest bits up, and the highest 5 bits are the shared exponent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[repr(transparent)]
+pub struct Rgb9e5(pub u32);
+/// a positive `Vec3` packed into radiance rgbe. the first 3 bytes are mantissas and the last is the
+/// shared exponent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[repr(transparent)]
+pub struct Rgbe(pub [u8; 4]);
+
+/// packs `value` as a normalized signed integer with `bits` bits.
+fn encode_snorm(value: f32, bits: u32) -> u32 {
+    let max = ((1 << (bits - 1)) - 1) as f32;
+    ((value.clamp(-1.0, 1.0) * max).round() as i32 as u32) & ((1 << bits) - 1)
+}
+fn decode_snorm(packed: u32, bits: u32) -> f32 {
+    let max = ((1 << (bits - 1)) - 1) as f32;
+    // moves the sign bit to the top so the shift back copies it
+    let value = ((packed << (32 - bits)) as i32) >> (32 - bits);
+    (value as f32 / max).max(-1.0)
+}
+
+const RGB9E5_MANTISSA_BITS: i32 = 9;
+const RGB9E5_BIAS: i32 = 15;
+const RGB9E5_MAX: f32 = 511.0 / 512.0 * (1 << 16) as f32;
+const RGBE_BIAS: i32 = 128;
+const RGBE_MAX: f32 = 255.0 * (1u128 << 119) as f32;
+
+impl Vec4 {
+    /// packs into the `GL_INT_2_10_10_10_REV` format. components are clamped from -1.0 to 1.0, and w
+    /// can only be -1.0, 0.0 or 1.0.
+    pub fn to_int_2101010_rev(self) -> Int2101010Rev {
+        Int2101010Rev(
+            encode_snorm(self.x, 10) | encode_snorm(self.y, 10) << 10 | encode_snorm(self.z, 10) << 20
+                | encode_snorm(self.w, 2) << 30
+        )
+    }
+    /// unpacks from the `GL_INT_2_10_10_10_REV` format.
+    pub fn from_int_2101010_rev(packed: Int2101010Rev) -> Self {
+        let p = packed.0;
+        vec4(decode_snorm(p, 10), decode_snorm(p >> 10, 10), decode_snorm(p >> 20, 10), decode_snorm(p >> 30, 2))
+    }
+}
+impl Vec3 {
+    /// packs into the `GL_INT_2_10_10_10_REV` format with w as 0.0. components are clamped from
+    /// -1.0 to 1.0 and are within 1 / 1022 of the original.
+    pub fn to_int_2101010_rev(self) -> Int2101010Rev { self.extend(0.0).to_int_2101010_rev() }
+    /// unpacks from the `GL_INT_2_10_10_10_REV` format, ignoring w.
+    pub fn from_int_2101010_rev(packed: Int2101010Rev) -> Self { Vec4::from_int_2101010_rev(packed).truncate() }
+    /// folds a normalised vector onto a square, which is stored as 2 normalized 16 bit integers.
+    /// the decoded direction is within 1e-4 radians of the original.
+    pub fn to_octahedral(self) -> Snorm16x2 {
+        let p = self.truncate() / (self.x.abs() + self.y.abs() + self.z.abs());
+        let p = if self.z < 0.0 {
+            // the lower half of the octahedron is folded over the diagonals
+            let sign = vec2(if p.x >= 0.0 { 1.0 } else { -1.0 }, if p.y >= 0.0 { 1.0 } else { -1.0 });
+            (Vec2::ONE - vec2(p.y.abs(), p.x.abs())) * sign
+        } else { p };
+        p.into()
+    }
+    /// unfolds an octahedral encoded vector. the result is normalised.
+    pub fn from_octahedral(encoded: Snorm16x2) -> Self {
+        let p = Vec2::from(encoded);
+        let mut v = vec3(p.x, p.y, 1.0 - p.x.abs() - p.y.abs());
+        let t = (-v.z).max(0.0);
+        v.x += if v.x >= 0.0 { -t } else { t };
+        v.y += if v.y >= 0.0 { -t } else { t };
+        v.normalise()
+    }
+    /// packs into the `GL_RGB9_E5` format. components are clamped from 0.0 to 65408.0, and each is
+    /// within 1 / 512 of the largest component (or 2^-25 for tiny values) of the original.
+    pub fn to_rgb9e5(self) -> Rgb9e5 {
+        let [r, g, b] = [self.x, self.y, self.z].map(|c| if c > 0.0 { c.min(RGB9E5_MAX) } else { 0.0 });
+        let max = r.max(g).max(b);
+        let mut exponent = (max.log2().floor() as i32).max(-RGB9E5_BIAS - 1) + 1 + RGB9E5_BIAS;
+        let step = |exponent: i32| 2f32.powi(exponent - RGB9E5_BIAS - RGB9E5_MANTISSA_BITS);
+        // rounding the largest component up can need 1 more bit
+        if (max / step(exponent) + 0.5).floor() as u32 == 1 << RGB9E5_MANTISSA_BITS { exponent += 1 }
+        let [r, g, b] = [r, g, b].map(|c| (c / step(exponent) + 0.5).floor() as u32);
+        Rgb9e5(r | g << 9 | b << 18 | (exponent as u32) << 27)
+    }
+    /// unpacks from the `GL_RGB9_E5` format.
+    pub fn from_rgb9e5(packed: Rgb9e5) -> Self {
+        let p = packed.0;
+        let step = 2f32.powi((p >> 27) as i32 - RGB9E5_BIAS - RGB9E5_MANTISSA_BITS);
+        vec3((p & 511) as f32, (p >> 9 & 511) as f32, (p >> 18 & 511) as f32) * step
+    }
+    /// packs into radiance rgbe. components are clamped from 0.0 to about 1.7e38, and each is within
+    /// 1 / 256 of the largest component of the original.
+    pub fn to_rgbe(self) -> Rgbe {
+        let [r, g, b] = [self.x, self.y, self.z].map(|c| if c > 0.0 { c.min(RGBE_MAX) } else { 0.0 });
+        let max = r.max(g).max(b);
+        if max < 1e-32 { return Rgbe([0; 4]) }
+        // the exponent where max / 2^exponent is from 0.5 to 1.0
+        let mut exponent = max.log2().floor() as i32 + 1;
+        if max / 2f32.powi(exponent) >= 1.0 { exponent += 1 }
+        if max / 2f32.powi(exponent) < 0.5 { exponent -= 1 }
+        let scale = 256.0 / 2f32.powi(exponent);
+        let [r, g, b] = [r, g, b].map(|c| (c * scale) as u8);
+        Rgbe([r, g, b, (exponent + RGBE_BIAS).clamp(0, 255) as u8])
+    }
+    /// unpacks from radiance rgbe.
+    pub fn from_rgbe(packed: Rgbe) -> Self {
+        let [r, g, b, exponent] = packed.0;
+        if exponent == 0 { return Vec3::ZERO }
+        let step = 2f32.powi(exponent as i32 - RGBE_BIAS - 8);
+        // the middle of each step, since packing rounds down
+        (vec3(r as f32, g as f32, b as f32) + Vec3::splat(0.5)) * step
+    }
+}
+impl From<Vec4> for Int2101010Rev {
+    fn from(value: Vec4) -> Self { value.to_int_2101010_rev() }
+}
+impl From<Int2101010Rev> for Vec4 {
+    fn from(value: Int2101010Rev) -> Self { Vec4::from_int_2101010_rev(value) }
+}
+impl From<Vec3> for Rgb9e5 {
+    fn from(value: Vec3) -> Self { value.to_rgb9e5() }
+}
+impl From<Rgb9e5> for Vec3 {
+    fn from(value: Rgb9e5) -> Self { Vec3::from_rgb9e5(value) }
+}
+impl From<Vec3> for Rgbe {
+    fn from(value: Vec3) -> Self { value.to_rgbe() }
+}
+impl From<Rgbe> for Vec3 {
+    fn from(value: Rgbe) -> Self { Vec3::from_rgbe(value) }
+}
+
+#[cfg(test)]
+mod tests {
+    use glium::{Vertex, vertex::AttributeType};
+    use crate::vert_types::{Int2101010RevNormal, OctahedralNormal};
+    use super::*;
+    /// points spread over the sphere.
+    fn directions() -> impl Iterator<Item = Vec3> {
+        (0..2000).map(|i| {
+            let z = 1.0 - (i as f32 + 0.5) / 1000.0;
+            let angle = i as f32 * 2.399963;
+            let r = (1.0 - z * z).sqrt();
+            vec3(r * angle.cos(), r * angle.sin(), z)
+        }).chain([Vec3::X, -Vec3::X, Vec3::Y, -Vec3::Y, Vec3::Z, -Vec3::Z])
+    }
+    /// colours from very dark to very bright.
+    fn colours() -> impl Iterator<Item = Vec3> {
+        (0..600).map(|i| {
+            let brightness = 2f32.powf(i as f32 / 20.0 - 20.0);
+            vec3(1.0, (i % 7) as f32 / 7.0, (i % 13) as f32 / 13.0) * brightness
+        })
+    }
+    #[test]
+    fn int_2101010_rev() {
+        for v in directions() {
+            let decoded = Vec3::from_int_2101010_rev(v.to_int_2101010_rev());
+            assert!((decoded - v).abs().max_element() <= 0.5 / 511.0 + 1e-6, "{v:?} {decoded:?}");
+        }
+        let v = vec4(1.0, -1.0, 0.0, -1.0);
+        assert!(Vec4::from_int_2101010_rev(v.to_int_2101010_rev()) == v);
+        assert!(vec4(1.0, 0.0, -1.0, 1.0).to_int_2101010_rev() == Int2101010Rev(0b01_1000000001_0000000000_0111111111));
+        // the smallest value is below -1.0, so it is treated as -1.0
+        assert!(Vec4::from_int_2101010_rev(Int2101010Rev(0b10_1000000000 << 20)) == vec4(0.0, 0.0, -1.0, -1.0));
+    }
+    #[test]
+    fn octahedral() {
+        let mut max_error: f32 = 0.0;
+        for v in directions() {
+            let decoded = Vec3::from_octahedral(v.to_octahedral());
+            // acos is too imprecise near 1.0, so the sine of the angle is used
+            max_error = max_error.max(decoded.cross(v).length());
+            assert!((decoded.length() - 1.0).abs() < 1e-6);
+        }
+        assert!(max_error < 1e-4, "{max_error}");
+        assert!(Vec3::from_octahedral(Vec3::Z.to_octahedral()) == Vec3::Z);
+        assert!(Vec3::from_octahedral((-Vec3::Z).to_octahedral()) == -Vec3::Z);
+    }
+    #[test]
+    fn rgb9e5() {
+        for v in colours().filter(|v| v.x <= RGB9E5_MAX) {
+            let decoded = Vec3::from_rgb9e5(v.to_rgb9e5());
+            let bound = (v.max_element() / 512.0).max(2f32.powi(-25)) * (1.0 + 1e-6);
+            assert!((decoded - v).abs().max_element() <= bound, "{v:?} {decoded:?}");
+        }
+        assert!(Vec3::from_rgb9e5(vec3(1.0, 0.5, 0.0).to_rgb9e5()) == vec3(1.0, 0.5, 0.0));
+        assert!(Vec3::from_rgb9e5(vec3(-1.0, f32::NAN, 1e9).to_rgb9e5()) == vec3(0.0, 0.0, RGB9E5_MAX));
+        // 511.9 rounds up to 512, which needs the next exponent
+        assert!(Vec3::from_rgb9e5(vec3(511.9, 0.0, 0.0).to_rgb9e5()) == vec3(512.0, 0.0, 0.0));
+    }
+    #[test]
+    fn rgbe() {
+        for v in colours() {
+            let decoded = Vec3::from_rgbe(v.to_rgbe());
+            assert!((decoded - v).abs().max_element() <= v.max_element() / 256.0, "{v:?} {decoded:?}");
+        }
+        assert!(vec3(1.0, 0.5, 0.0).to_rgbe() == Rgbe([128, 64, 0, 129]));
+        assert!(Vec3::ZERO.to_rgbe() == Rgbe([0; 4]) && Vec3::from_rgbe(Rgbe([0; 4])) == Vec3::ZERO);
+        assert!(vec3(-1.0, 2.0, 0.0).to_rgbe() == Rgbe([0, 128, 0, 130]));
+        // values too big for rgbe are clamped to the largest one
+        assert!(vec3(f32::INFINITY, 1.0, f32::NAN).to_rgbe() == Rgbe([255, 0, 0, 255]));
+        let decoded = Vec3::from_rgbe(vec3(f32::MAX, 1.0, 0.0).to_rgbe());
+        assert!((decoded.x - RGBE_MAX).abs() <= RGBE_MAX / 256.0 && decoded.y <= RGBE_MAX / 256.0, "{decoded:?}");
+        assert!(decoded.y == decoded.z);
+    }
+    #[test]
+    fn vertex_formats() {
+        let [(name, _, _, ty, normalize)] = Int2101010RevNormal::build_bindings() else { panic!() };
+        assert_eq!((name.as_ref(), *ty, *normalize), ("normal", AttributeType::I2I10I10I10Reversed, true));
+        let [(name, _, _, ty, normalize)] = OctahedralNormal::build_bindings() else { panic!() };
+        assert_eq!((name.as_ref(), *ty, *normalize), ("normal", AttributeType::I16I16, true));
+        assert_eq!(std::mem::size_of::<Int2101010RevNormal>(), 4);
+        assert_eq!(std::mem::size_of::<OctahedralNormal>(), 4);
+        assert_eq!(AttributeType::U8U8U8U8.get_size_bytes(), std::mem::size_of::<Rgbe>());
+    }
+}
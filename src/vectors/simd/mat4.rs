@@ -0,0 +1,35 @@
+use crate::matrices::Mat4;
+use super::{F32x4, combine_columns};
+
+impl Mat4 {
+    fn simd_op(self, rhs: Self, op: fn(F32x4, F32x4) -> F32x4) -> Self {
+        let a = self.into_column_major_array();
+        let b = rhs.into_column_major_array();
+        Mat4::from_column_major_array(std::array::from_fn(|i|
+            op(F32x4::from_array(a[i]), F32x4::from_array(b[i])).to_array()
+        ))
+    }
+    pub fn scale(self, scalar: f32) -> Mat4 {
+        let scalar = F32x4::splat(scalar);
+        Mat4::from_column_major_array(self.into_column_major_array().map(|column|
+            F32x4::from_array(column).mul(scalar).to_array()
+        ))
+    }
+}
+impl std::ops::Mul for Mat4 {
+    fn mul(self, rhs: Self) -> Self::Output {
+        let columns = self.into_column_major_array().map(F32x4::from_array);
+        Mat4::from_column_major_array(rhs.into_column_major_array().map(|column|
+            combine_columns(columns, column).to_array()
+        ))
+    }
+    type Output = Self;
+}
+impl std::ops::Add for Mat4 {
+    fn add(self, rhs: Self) -> Self::Output { self.simd_op(rhs, F32x4::add) }
+    type Output = Self;
+}
+impl std::ops::Sub for Mat4 {
+    fn sub(self, rhs: Self) -> Self::Output { self.simd_op(rhs, F32x4::sub) }
+    type Output = Self;
+}
@@ -1,24 +1,168 @@
-mod vec2;
+//! simd arithmetic for `Vec3`, `Vec4` and `Mat4`, used instead of the scalar code when the `simd`
+//! feature is enabled. uses sse2 on x86, neon on aarch64 and plain arrays everywhere else.
+//!
+//! every operation is done in the same order as the scalar code and without fused multiply-adds,
+//! so results are bit for bit the same as with the feature disabled.
+// newer compilers allow calling some intrinsics outside of unsafe blocks
+#![allow(unused_unsafe)]
 mod vec3;
 mod vec4;
-pub use vec2::{Vec2, vec2};
-pub use vec3::{Vec3, vec3};
-pub use vec4::{Vec4, vec4};
-mod dvec2;
-mod dvec3;
-mod dvec4;
-pub use dvec2::{DVec2, dvec2};
-pub use dvec3::{DVec3, dvec3};
-pub use dvec4::{DVec4, dvec4};
-mod ivec2;
-mod ivec3;
-mod ivec4;
-pub use ivec2::{IVec2, ivec2};
-pub use ivec3::{IVec3, ivec3};
-pub use ivec4::{IVec4, ivec4};
-mod uvec2;
-mod uvec3;
-mod uvec4;
-pub use uvec2::{UVec2, uvec2};
-pub use uvec3::{UVec3, uvec3};
-pub use uvec4::{UVec4, uvec4};
+mod mat4;
+
+#[cfg(all(target_arch = "x86", target_feature = "sse2"))]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+#[cfg(target_arch = "aarch64")]
+use std::arch::aarch64::*;
+
+#[cfg(any(target_arch = "x86_64", all(target_arch = "x86", target_feature = "sse2")))]
+type Inner = __m128;
+#[cfg(target_arch = "aarch64")]
+type Inner = float32x4_t;
+#[cfg(not(any(target_arch = "x86_64", all(target_arch = "x86", target_feature = "sse2"), target_arch = "aarch64")))]
+type Inner = [f32; 4];
+
+/// 4 floats stored in a simd register
+#[derive(Clone, Copy)]
+pub(crate) struct F32x4(Inner);
+
+#[cfg(any(target_arch = "x86_64", all(target_arch = "x86", target_feature = "sse2")))]
+impl F32x4 {
+    pub fn from_array(array: [f32; 4]) -> Self { unsafe { Self(_mm_loadu_ps(array.as_ptr())) } }
+    pub fn to_array(self) -> [f32; 4] {
+        let mut array = [0.0; 4];
+        unsafe { _mm_storeu_ps(array.as_mut_ptr(), self.0) }
+        array
+    }
+    pub fn splat(value: f32) -> Self { unsafe { Self(_mm_set1_ps(value)) } }
+    pub fn add(self, rhs: Self) -> Self { unsafe { Self(_mm_add_ps(self.0, rhs.0)) } }
+    pub fn sub(self, rhs: Self) -> Self { unsafe { Self(_mm_sub_ps(self.0, rhs.0)) } }
+    pub fn mul(self, rhs: Self) -> Self { unsafe { Self(_mm_mul_ps(self.0, rhs.0)) } }
+    pub fn div(self, rhs: Self) -> Self { unsafe { Self(_mm_div_ps(self.0, rhs.0)) } }
+}
+#[cfg(target_arch = "aarch64")]
+impl F32x4 {
+    pub fn from_array(array: [f32; 4]) -> Self { unsafe { Self(vld1q_f32(array.as_ptr())) } }
+    pub fn to_array(self) -> [f32; 4] {
+        let mut array = [0.0; 4];
+        unsafe { vst1q_f32(array.as_mut_ptr(), self.0) }
+        array
+    }
+    pub fn splat(value: f32) -> Self { unsafe { Self(vdupq_n_f32(value)) } }
+    pub fn add(self, rhs: Self) -> Self { unsafe { Self(vaddq_f32(self.0, rhs.0)) } }
+    pub fn sub(self, rhs: Self) -> Self { unsafe { Self(vsubq_f32(self.0, rhs.0)) } }
+    pub fn mul(self, rhs: Self) -> Self { unsafe { Self(vmulq_f32(self.0, rhs.0)) } }
+    pub fn div(self, rhs: Self) -> Self { unsafe { Self(vdivq_f32(self.0, rhs.0)) } }
+}
+#[cfg(not(any(target_arch = "x86_64", all(target_arch = "x86", target_feature = "sse2"), target_arch = "aarch64")))]
+impl F32x4 {
+    pub fn from_array(array: [f32; 4]) -> Self { Self(array) }
+    pub fn to_array(self) -> [f32; 4] { self.0 }
+    pub fn splat(value: f32) -> Self { Self([value; 4]) }
+    pub fn add(self, rhs: Self) -> Self { Self(std::array::from_fn(|i| self.0[i] + rhs.0[i])) }
+    pub fn sub(self, rhs: Self) -> Self { Self(std::array::from_fn(|i| self.0[i] - rhs.0[i])) }
+    pub fn mul(self, rhs: Self) -> Self { Self(std::array::from_fn(|i| self.0[i] * rhs.0[i])) }
+    pub fn div(self, rhs: Self) -> Self { Self(std::array::from_fn(|i| self.0[i] / rhs.0[i])) }
+}
+/// multiplies the columns by the components of `vector` and adds them together. this is the same
+/// as multiplying a column major matrix by a vector.
+pub(crate) fn combine_columns(columns: [F32x4; 4], vector: [f32; 4]) -> F32x4 {
+    columns[0].mul(F32x4::splat(vector[0]))
+        .add(columns[1].mul(F32x4::splat(vector[1])))
+        .add(columns[2].mul(F32x4::splat(vector[2])))
+        .add(columns[3].mul(F32x4::splat(vector[3])))
+}
+/// implements the component wise operators that `CmpOps` would otherwise derive
+macro_rules! impl_simd_ops {
+    ($vec:ident, $from:expr, $into:expr) => {
+        impl $vec {
+            fn simd_op(self, rhs: Self, op: fn(F32x4, F32x4) -> F32x4) -> Self {
+                let from: fn(Self) -> F32x4 = $from;
+                let into: fn(F32x4) -> Self = $into;
+                into(op(from(self), from(rhs)))
+            }
+        }
+        impl std::ops::Add for $vec {
+            fn add(self, rhs: Self) -> Self::Output { self.simd_op(rhs, F32x4::add) }
+            type Output = Self;
+        }
+        impl std::ops::AddAssign for $vec { fn add_assign(&mut self, rhs: Self) { *self = *self + rhs } }
+        impl std::ops::Sub for $vec {
+            fn sub(self, rhs: Self) -> Self::Output { self.simd_op(rhs, F32x4::sub) }
+            type Output = Self;
+        }
+        impl std::ops::SubAssign for $vec { fn sub_assign(&mut self, rhs: Self) { *self = *self - rhs } }
+        impl std::ops::Mul for $vec {
+            fn mul(self, rhs: Self) -> Self::Output { self.simd_op(rhs, F32x4::mul) }
+            type Output = Self;
+        }
+        impl std::ops::MulAssign for $vec { fn mul_assign(&mut self, rhs: Self) { *self = *self * rhs } }
+        impl std::ops::Div for $vec {
+            fn div(self, rhs: Self) -> Self::Output { self.simd_op(rhs, F32x4::div) }
+            type Output = Self;
+        }
+        impl std::ops::DivAssign for $vec { fn div_assign(&mut self, rhs: Self) { *self = *self / rhs } }
+    };
+}
+use impl_simd_ops;
+
+#[cfg(test)]
+mod tests {
+    use glium::uniforms::{AsUniformValue, UniformValue};
+    use crate::{prelude::*, testing::Random};
+    fn bits<const N: usize>(array: [f32; N]) -> [u32; N] { array.map(f32::to_bits) }
+    fn mat_bits(mat: Mat4) -> [[u32; 4]; 4] { mat.into_column_major_array().map(bits) }
+    #[test]
+    fn vec3_parity() {
+        let mut rng = Random::new();
+        for _ in 0..1000 {
+            let (a, b) = (rng.vec3(), rng.vec3());
+            let [ax, ay, az]: [f32; 3] = a.into();
+            let [bx, by, bz]: [f32; 3] = b.into();
+            assert_eq!(bits((a + b).into()), bits([ax + bx, ay + by, az + bz]));
+            assert_eq!(bits((a - b).into()), bits([ax - bx, ay - by, az - bz]));
+            assert_eq!(bits((a * b).into()), bits([ax * bx, ay * by, az * bz]));
+            assert_eq!(bits((a / b).into()), bits([ax / bx, ay / by, az / bz]));
+            assert_eq!(bits((a % b).into()), bits([ax % bx, ay % by, az % bz]));
+            assert_eq!(bits((-a).into()), bits([-ax, -ay, -az]));
+        }
+    }
+    #[test]
+    fn vec4_parity() {
+        let mut rng = Random::new();
+        for _ in 0..1000 {
+            let (a, b) = (rng.vec4(), rng.vec4());
+            let [ax, ay, az, aw]: [f32; 4] = a.into();
+            let [bx, by, bz, bw]: [f32; 4] = b.into();
+            assert_eq!(bits((a + b).into()), bits([ax + bx, ay + by, az + bz, aw + bw]));
+            assert_eq!(bits((a - b).into()), bits([ax - bx, ay - by, az - bz, aw - bw]));
+            assert_eq!(bits((a * b).into()), bits([ax * bx, ay * by, az * bz, aw * bw]));
+            assert_eq!(bits((a / b).into()), bits([ax / bx, ay / by, az / bz, aw / bw]));
+            assert_eq!(bits((-a).into()), bits([-ax, -ay, -az, -aw]));
+        }
+    }
+    #[test]
+    fn mat4_parity() {
+        let mut rng = Random::new();
+        for _ in 0..1000 {
+            let (a, b, v, s) = (rng.mat4(), rng.mat4(), rng.vec4(), rng.f32());
+            let (ca, cb) = (a.into_column_major_array(), b.into_column_major_array());
+
+            let product: [[f32; 4]; 4] = std::array::from_fn(|x| std::array::from_fn(|y|
+                ca[0][y]*cb[x][0] + ca[1][y]*cb[x][1] + ca[2][y]*cb[x][2] + ca[3][y]*cb[x][3]
+            ));
+            assert_eq!(mat_bits(a * b), product.map(bits));
+            let transformed: [f32; 4] = std::array::from_fn(|y|
+                ca[0][y]*v.x + ca[1][y]*v.y + ca[2][y]*v.z + ca[3][y]*v.w
+            );
+            assert_eq!(bits((a * v).into()), bits(transformed));
+            assert_eq!(mat_bits(a + b), std::array::from_fn(|x| bits(std::array::from_fn(|y| ca[x][y] + cb[x][y]))));
+            assert_eq!(mat_bits(a - b), std::array::from_fn(|x| bits(std::array::from_fn(|y| ca[x][y] - cb[x][y]))));
+            assert_eq!(mat_bits(a * s), ca.map(|c| bits(c.map(|v| v * s))));
+
+            let UniformValue::Mat4(uniform) = (a * b).as_uniform_value() else { panic!() };
+            assert_eq!(uniform.map(bits), product.map(bits));
+        }
+    }
+}
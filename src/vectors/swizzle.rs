@@ -0,0 +1,99 @@
+//! glsl style swizzles. every vector gets a getter for each combination of 2, 3 and 4 of its
+//! components (e.g `zyx()`) and a setter for each combination without repeats (e.g `set_xz()`),
+//! named with both `xyzw` and `rgba`.
+use paste::paste;
+use super::*;
+
+/// creates a getter for every combination (with repeats) of the components in the option list.
+/// each option is a `(name field)` pair so that the `rgba` names can map onto `xyzw` fields.
+macro_rules! swizzle_getters {
+    // every slot has been filled, create the getter
+    ($out:ident; [$(($name:ident $field:ident))*]; []; $options:tt; $all:tt) => {
+        paste! {
+            #[doc = concat!("returns a `", stringify!($out), "` made from the `", $(stringify!($name),)* "` components.")]
+            pub const fn [<$($name)*>](self) -> $out { $out::new($(self.$field),*) }
+        }
+    };
+    (@push $out:ident; [$($chosen:tt)*]; $option:tt; [_ $($slots:tt)*]; $all:tt) => {
+        swizzle_getters!($out; [$($chosen)* $option]; [$($slots)*]; $all; $all);
+    };
+    // fill the next slot with each option
+    ($out:ident; $chosen:tt; $slots:tt; [$($option:tt)*]; $all:tt) => {
+        $( swizzle_getters!(@push $out; $chosen; $option; $slots; $all); )*
+    };
+}
+/// creates a setter for every combination (without repeats) of the components in the option list.
+/// options are split into the ones before and after the one currently being considered.
+macro_rules! swizzle_setters {
+    // every slot has been filled, create the setter
+    ($in:ident { $($in_field:ident)* }; [$(($name:ident $field:ident))*]; []; $before:tt; $after:tt) => {
+        paste! {
+            #[doc = concat!("sets the `", $(stringify!($name),)* "` components to the components of `value`.")]
+            pub fn [<set_ $($name)*>](&mut self, value: $in) { $(self.$field = value.$in_field;)* }
+        }
+    };
+    // no options left for this slot
+    ($in:ident $in_fields:tt; $chosen:tt; [_ $($slots:tt)*]; $before:tt; []) => {};
+    // fill the next slot with the current option, then move on to the next option
+    ($in:ident $in_fields:tt; [$($chosen:tt)*]; [_ $($slots:tt)*]; [$($before:tt)*]; [$option:tt $($after:tt)*]) => {
+        swizzle_setters!($in $in_fields; [$($chosen)* $option]; [$($slots)*]; []; [$($before)* $($after)*]);
+        swizzle_setters!($in $in_fields; [$($chosen)*]; [_ $($slots)*]; [$($before)* $option]; [$($after)*]);
+    };
+}
+macro_rules! impl_swizzles {
+    ($vec:ident => $vec2:ident, $vec3:ident, $vec4:ident; $($components:tt),*) => {
+        impl $vec {
+            $(
+                swizzle_getters!($vec2; []; [_ _]; $components; $components);
+                swizzle_getters!($vec3; []; [_ _ _]; $components; $components);
+                swizzle_getters!($vec4; []; [_ _ _ _]; $components; $components);
+                swizzle_setters!($vec2 { x y }; []; [_ _]; []; $components);
+                swizzle_setters!($vec3 { x y z }; []; [_ _ _]; []; $components);
+                swizzle_setters!($vec4 { x y z w }; []; [_ _ _ _]; []; $components);
+            )*
+        }
+    };
+}
+macro_rules! impl_family_swizzles {
+    ($vec2:ident, $vec3:ident, $vec4:ident) => {
+        impl_swizzles!($vec2 => $vec2, $vec3, $vec4;
+            [(x x) (y y)], [(r x) (g y)]);
+        impl_swizzles!($vec3 => $vec2, $vec3, $vec4;
+            [(x x) (y y) (z z)], [(r x) (g y) (b z)]);
+        impl_swizzles!($vec4 => $vec2, $vec3, $vec4;
+            [(x x) (y y) (z z) (w w)], [(r x) (g y) (b z) (a w)]);
+    };
+}
+impl_family_swizzles!(Vec2, Vec3, Vec4);
+impl_family_swizzles!(DVec2, DVec3, DVec4);
+impl_family_swizzles!(IVec2, IVec3, IVec4);
+impl_family_swizzles!(UVec2, UVec3, UVec4);
+impl_family_swizzles!(DIVec2, DIVec3, DIVec4);
+impl_family_swizzles!(DUVec2, DUVec3, DUVec4);
+impl_family_swizzles!(BVec2, BVec3, BVec4);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn swizzle_getters() {
+        let a = vec4(1.0, 2.0, 3.0, 4.0);
+        assert!(a.zyx() == vec3(3.0, 2.0, 1.0));
+        assert!(a.xxyy() == vec4(1.0, 1.0, 2.0, 2.0));
+        assert!(a.bgra() == a.zyxw());
+        assert!(ivec2(1, 2).yxyx() == ivec4(2, 1, 2, 1));
+        let b = bvec3(true, false, false).zzx();
+        assert!(!b.x && !b.y && b.z);
+    }
+    #[test]
+    fn swizzle_setters() {
+        let mut a = uvec4(1, 2, 3, 4);
+        a.set_wx(uvec2(5, 6));
+        assert!(a == uvec4(6, 2, 3, 5));
+        a.set_gbr(uvec3(7, 8, 9));
+        assert!(a == uvec4(9, 7, 8, 5));
+        let mut b = dvec3(1.0, 2.0, 3.0);
+        b.set_zyx(b);
+        assert!(b == dvec3(3.0, 2.0, 1.0));
+    }
+}
@@ -3,10 +3,13 @@ use glium::uniforms::AsUniformValue;
 
 use super::{uvec3::*, vec2::Vec2, bvec2::*};
 #[derive(
-    Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, CmpAdd, CmpAddAssign, CmpDiv,
-    CmpDivAssign, CmpMul, CmpMulAssign, CmpRem, CmpRemAssign, CmpSub, CmpSubAssign
+    Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash, Default, CmpAdd, CmpAddAssign,
+    CmpDiv, CmpDivAssign, CmpMul, CmpMulAssign, CmpRem, CmpRemAssign, CmpSub, CmpSubAssign
 )]
 /// an unsigned interger vector made from a x and y coordinate.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[repr(C)]
 pub struct UVec2 {
     pub x: u32,
     pub y: u32,
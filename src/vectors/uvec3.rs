@@ -2,10 +2,13 @@ use derive_cmp_ops::*;
 use glium::uniforms::AsUniformValue;
 use super::{vec3::Vec3, uvec2::*, uvec4::*, bvec3::*};
 #[derive(
-    Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, CmpAdd, CmpAddAssign, CmpDiv,
-    CmpDivAssign, CmpMul, CmpMulAssign, CmpRem, CmpRemAssign, CmpSub, CmpSubAssign
+    Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash, Default, CmpAdd, CmpAddAssign,
+    CmpDiv, CmpDivAssign, CmpMul, CmpMulAssign, CmpRem, CmpRemAssign, CmpSub, CmpSubAssign
 )]
 /// an unsigned interger vector made from a x, y and z coordinate.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[repr(C)]
 pub struct UVec3 {
     pub x: u32,
     pub y: u32,
@@ -5,6 +5,9 @@ use crate::matrices::Mat2;
 use super::{vec3::{vec3, Vec3}, bvec2::*};
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd, CmpOps)]
 /// a vector made from a x and y coordinate.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[repr(C)]
 pub struct Vec2 {
     pub x: f32,
     pub y: f32,
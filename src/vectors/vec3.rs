@@ -1,9 +1,12 @@
-use derive_cmp_ops::CmpOps;
 use glium::uniforms::AsUniformValue;
 use crate::prelude::Mat3;
 use super::{vec2::{vec2, Vec2}, vec4::{vec4, Vec4}, bvec3::*};
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, CmpOps)]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(not(feature = "simd"), derive(derive_cmp_ops::CmpOps))]
 ///a vector made from a x, y and z coordinate.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[repr(C)]
 pub struct Vec3{
     pub x: f32,
     pub y: f32,
@@ -72,6 +75,16 @@ impl Vec3{
         if length == 0.0 { return vec3(0.0, 0.0, 0.0); }
         self.scale(1.0 / length)
     }
+    ///returns 2 normalised vectors that are perpendicular to each other and to `self`. `self` must be
+    ///normalised.
+    pub fn any_orthonormal_basis(self) -> (Vec3, Vec3) {
+        // from "building an orthonormal basis, revisited" by duff et al.
+        let Vec3 { x, y, z } = self;
+        let sign = 1.0f32.copysign(z);
+        let a = -1.0 / (sign + z);
+        let b = x * y * a;
+        (vec3(1.0 + sign * x * x * a, sign * b, -sign * x), vec3(b, sign + y * y * a, -y))
+    }
     ///transforms vector by the matrix
     pub fn transform(self, matrix: Mat3) -> Self{
         let a: Vec3 = matrix.row(0).into();
@@ -1,9 +1,13 @@
-use derive_cmp_ops::CmpOps;
 use glium::uniforms::AsUniformValue;
+#[cfg(not(feature = "simd"))]
 use crate::prelude::Mat4;
 use super::{vec3::{vec3, Vec3}, bvec4::*};
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, CmpOps)]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(not(feature = "simd"), derive(derive_cmp_ops::CmpOps))]
 ///a vector made from a x, y, z and w coordinate.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[repr(C)]
 pub struct Vec4{
     pub x: f32,
     pub y: f32,
@@ -60,6 +64,7 @@ impl Vec4{
         self.scale(1.0 / length)
     }
     ///transforms vector by the matrix
+    #[cfg(not(feature = "simd"))]
     pub fn transform(self, matrix: Mat4) -> Vec4{
         let a: Vec4 = matrix.row(0).into();
         let b: Vec4 = matrix.row(1).into();
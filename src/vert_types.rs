@@ -4,6 +4,7 @@ use crate::prelude::*;
 #[derive(Debug, Clone, Copy)]
 /// a vertex used for rendering by glium. stores vertex position. equivelant to `position` in the supplied
 /// vertex shader and `v_position` in fragment shader.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Vertex{
     pub position: (f32, f32, f32)
 }
@@ -17,11 +18,15 @@ impl From<Vec3> for Vertex {
         Vertex { position: (value.x, value.y, value.z) }
     }
 }
+impl From<Vertex> for Vec3 {
+    fn from(value: Vertex) -> Self { value.position.into() }
+}
 implement_vertex!(Vertex, position);
 
 #[derive(Debug, Clone, Copy)]
 /// a normal used for rendering by glium. stores vertex direction. equivelant to `normal` in the supplied
 /// vertex shader and `v_normal` in fragment shader.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Normal {
     pub normal: (f32, f32, f32)
 }
@@ -40,6 +45,7 @@ implement_vertex!(Normal, normal);
 #[derive(Clone, Copy, Debug)]
 /// a texture coordinate used for rendering by glium. also called a uv. stores uvs. equivelant to
 /// `texture_coords` in the supplied vertex shader and `uv` in fragment shader.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TextureCoords{
     pub texture_coords: (f32, f32)
 }
@@ -58,6 +64,7 @@ implement_vertex!(TextureCoords, texture_coords);
 #[derive(Clone, Copy, Debug)]
 /// a vertex colour used for rendering by glium. stores vertex colour. equivelant to `colour` in the supplied
 /// vertex shader and `v_colour` in fragment shader.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct VertexColour{
     pub colour: (f32, f32, f32, f32)
 }
@@ -73,6 +80,113 @@ impl VertexColour{
 }
 implement_vertex!(VertexColour, colour);
 
+#[derive(Debug, Clone, Copy)]
+/// a `Normal` stored in 6 bytes instead of 12. it uses the same `normal` attribute, so it works with
+/// the supplied vertex shader.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PackedNormal {
+    pub normal: Snorm16x3
+}
+impl From<Vec3> for PackedNormal {
+    fn from(value: Vec3) -> Self { Self { normal: value.into() } }
+}
+impl From<Normal> for PackedNormal {
+    fn from(value: Normal) -> Self { Vec3::from(value.normal).into() }
+}
+impl From<PackedNormal> for Normal {
+    fn from(value: PackedNormal) -> Self { Vec3::from(value.normal).into() }
+}
+impl PackedNormal {
+    pub fn new(x: f32, y: f32, z: f32) -> Self { vec3(x, y, z).into() }
+}
+implement_vertex!(PackedNormal, normal normalize(true));
+
+#[derive(Debug, Clone, Copy)]
+/// `TextureCoords` stored in 4 bytes instead of 8. it uses the same `texture_coords` attribute, so it
+/// works with the supplied vertex shader. uvs are clamped from 0.0 to 1.0, so it can't be used for
+/// textures that repeat across a face.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PackedTextureCoords {
+    pub texture_coords: Unorm16x2
+}
+impl From<Vec2> for PackedTextureCoords {
+    fn from(value: Vec2) -> Self { Self { texture_coords: value.into() } }
+}
+impl From<TextureCoords> for PackedTextureCoords {
+    fn from(value: TextureCoords) -> Self { Vec2::from(value.texture_coords).into() }
+}
+impl From<PackedTextureCoords> for TextureCoords {
+    fn from(value: PackedTextureCoords) -> Self { Vec2::from(value.texture_coords).into() }
+}
+impl PackedTextureCoords {
+    pub fn new(u: f32, v: f32) -> Self { vec2(u, v).into() }
+}
+implement_vertex!(PackedTextureCoords, texture_coords normalize(true));
+
+#[derive(Debug, Clone, Copy)]
+/// a `VertexColour` stored in 4 bytes instead of 16. it uses the same `colour` attribute, so it works
+/// with the supplied vertex shader. components are clamped from 0.0 to 1.0.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PackedVertexColour {
+    pub colour: Unorm8x4
+}
+impl From<Vec4> for PackedVertexColour {
+    fn from(value: Vec4) -> Self { Self { colour: value.into() } }
+}
+impl From<VertexColour> for PackedVertexColour {
+    fn from(value: VertexColour) -> Self { Vec4::from(value.colour).into() }
+}
+impl From<PackedVertexColour> for VertexColour {
+    fn from(value: PackedVertexColour) -> Self { Vec4::from(value.colour).into() }
+}
+impl PackedVertexColour {
+    pub fn new(r: f32, g: f32, b: f32, a: f32) -> Self { vec4(r, g, b, a).into() }
+}
+implement_vertex!(PackedVertexColour, colour normalize(true));
+
+#[derive(Debug, Clone, Copy)]
+/// a `Normal` stored in 4 bytes in the `GL_INT_2_10_10_10_REV` format. it uses the same `normal`
+/// attribute, so it works with the supplied vertex shader. each component is within 1 / 1022 of the
+/// original.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Int2101010RevNormal {
+    pub normal: Int2101010Rev
+}
+impl From<Vec3> for Int2101010RevNormal {
+    fn from(value: Vec3) -> Self { Self { normal: value.to_int_2101010_rev() } }
+}
+impl From<Normal> for Int2101010RevNormal {
+    fn from(value: Normal) -> Self { Vec3::from(value.normal).into() }
+}
+impl From<Int2101010RevNormal> for Normal {
+    fn from(value: Int2101010RevNormal) -> Self { Vec3::from_int_2101010_rev(value.normal).into() }
+}
+impl Int2101010RevNormal {
+    pub fn new(x: f32, y: f32, z: f32) -> Self { vec3(x, y, z).into() }
+}
+implement_vertex!(Int2101010RevNormal, normal normalize(true));
+
+#[derive(Debug, Clone, Copy)]
+/// a normalised `Normal` stored in 4 bytes with octahedral encoding. it uses the `normal` attribute
+/// as a `vec2`, so the vertex shader has to decode it with `shaders::OCTAHEDRAL_DECODE`.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OctahedralNormal {
+    pub normal: Snorm16x2
+}
+impl From<Vec3> for OctahedralNormal {
+    fn from(value: Vec3) -> Self { Self { normal: value.to_octahedral() } }
+}
+impl From<Normal> for OctahedralNormal {
+    fn from(value: Normal) -> Self { Vec3::from(value.normal).into() }
+}
+impl From<OctahedralNormal> for Normal {
+    fn from(value: OctahedralNormal) -> Self { Vec3::from_octahedral(value.normal).into() }
+}
+impl OctahedralNormal {
+    pub fn new(x: f32, y: f32, z: f32) -> Self { vec3(x, y, z).into() }
+}
+implement_vertex!(OctahedralNormal, normal normalize(true));
+
 #[derive(Debug)]
 pub enum MeshError {
     VertexErr(glium::vertex::BufferCreationError),
@@ -100,9 +214,11 @@ where F: Facade + ?Sized, V: glium::Vertex + Copy, I: Index {
 /// if an error occurs it returns the `MeshError` enum
 /// ```no_run
 /// use glium_types::{mesh, teapot};
+/// # fn load(display: &glium::Display<glium::glutin::surface::WindowSurface>) {
 /// let (indices, vertices, normals) = mesh!(
-///     &display, &teapot::INDICES, &teapot::VERTICES, &teapot::NORMALS
+///     display, &teapot::INDICES, &teapot::VERTICES, &teapot::NORMALS
 /// ).unwrap();
+/// # }
 /// ```
 #[macro_export]
 macro_rules! mesh {
@@ -128,3 +244,18 @@ macro_rules! mesh {
         }
     }
 }
+
+#[cfg(all(test, feature = "serde"))]
+mod serde_tests {
+    use super::*;
+    #[test]
+    fn json() {
+        let vertex: Vertex = crate::vectors::vec3(1.0, 2.0, 3.0).into();
+        let json = serde_json::to_string(&vertex).unwrap();
+        assert_eq!(json, r#"{"position":[1.0,2.0,3.0]}"#);
+        let vertex: Vertex = serde_json::from_str(&json).unwrap();
+        assert_eq!(vertex.position, (1.0, 2.0, 3.0));
+        let colour: VertexColour = serde_json::from_str(r#"{"colour":[1.0,0.5,0.0,1.0]}"#).unwrap();
+        assert_eq!(colour.colour, (1.0, 0.5, 0.0, 1.0));
+    }
+}